@@ -0,0 +1,461 @@
+//! Derive macros for `float_plus::ApproxEq` and `float_plus::ApproxEqSf`.
+//!
+//! Fields are compared with the derived trait, recursing into nested types. Per-field
+//! `#[approx(..)]` attributes adjust the comparison:
+//!
+//! - `#[approx(skip)]` ignores the field.
+//! - `#[approx(exact)]` compares the field with `PartialEq`.
+//! - `#[approx(decimals = 3)]` uses 3 decimals for the field when deriving `ApproxEq`.
+//! - `#[approx(sf = 4)]` uses 4 significant figures for the field when deriving `ApproxEqSf`.
+//!
+//! `decimals` and `sf` are rejected on a type that does not also implement the trait they apply
+//! to. Values of different enum variants are never equal.
+//!
+//! Each derive also implements the matching `float_plus::derive` diff trait, `ApproxEqDiff` or
+//! `ApproxEqSfDiff`, listing the paths of the mismatching fields.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, Generics, Ident, LitInt,
+    Result,
+};
+
+/// Derives `float_plus::ApproxEq` and the field-by-field `float_plus::derive::ApproxEqDiff`.
+#[proc_macro_derive(ApproxEq, attributes(approx))]
+pub fn derive_approx_eq(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input, &Comparison::Decimals)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `float_plus::ApproxEqSf` and the field-by-field `float_plus::derive::ApproxEqSfDiff`.
+#[proc_macro_derive(ApproxEqSf, attributes(approx))]
+pub fn derive_approx_eq_sf(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input, &Comparison::SignificantFigures)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Trait being derived.
+enum Comparison {
+    Decimals,
+    SignificantFigures,
+}
+
+impl Comparison {
+    fn trait_path(&self) -> TokenStream2 {
+        match self {
+            Comparison::Decimals => quote!(::float_plus::ApproxEq),
+            Comparison::SignificantFigures => quote!(::float_plus::ApproxEqSf),
+        }
+    }
+
+    /// Marker trait recording that this trait is derived.
+    fn marker_path(&self) -> TokenStream2 {
+        match self {
+            Comparison::Decimals => quote!(::float_plus::derive::DerivesApproxEq),
+            Comparison::SignificantFigures => quote!(::float_plus::derive::DerivesApproxEqSf),
+        }
+    }
+
+    fn diff_trait_path(&self) -> TokenStream2 {
+        match self {
+            Comparison::Decimals => quote!(::float_plus::derive::ApproxEqDiff),
+            Comparison::SignificantFigures => quote!(::float_plus::derive::ApproxEqSfDiff),
+        }
+    }
+
+    /// The other trait, whose precision attribute this derive does not apply.
+    fn other(&self) -> Comparison {
+        match self {
+            Comparison::Decimals => Comparison::SignificantFigures,
+            Comparison::SignificantFigures => Comparison::Decimals,
+        }
+    }
+
+    /// Precision attribute of a field that applies to this derive, with its span.
+    fn precision(&self, attributes: &FieldAttributes) -> Option<(u8, Span)> {
+        match self {
+            Comparison::Decimals => attributes.decimals,
+            Comparison::SignificantFigures => attributes.significant_figures,
+        }
+    }
+
+    /// Name of the tolerance argument in the generated methods.
+    fn tolerance(&self) -> Ident {
+        match self {
+            Comparison::Decimals => format_ident!("decimals"),
+            Comparison::SignificantFigures => format_ident!("significant_figures"),
+        }
+    }
+
+    /// Tolerance for a field, honouring its override.
+    fn field_tolerance(&self, attributes: &FieldAttributes) -> TokenStream2 {
+        if let Some((precision, _)) = self.precision(attributes) {
+            return quote!(#precision);
+        }
+
+        let tolerance = self.tolerance();
+        quote!(#tolerance)
+    }
+
+    /// Call comparing `first` with `second`.
+    fn eq_call(&self, first: &Ident, second: &Ident, tolerance: &TokenStream2) -> TokenStream2 {
+        let trait_path = self.trait_path();
+
+        match self {
+            Comparison::Decimals => {
+                quote!(#trait_path::aeq_with_policy(#first, #second, #tolerance, policy))
+            }
+            Comparison::SignificantFigures => quote!(
+                #trait_path::aeq_sf_mode_with_policy(#first, #second, #tolerance, mode, policy)
+            ),
+        }
+    }
+
+    /// Call listing the mismatching paths of `first` and `second`.
+    fn diff_call(&self, first: &Ident, second: &Ident, tolerance: &TokenStream2) -> TokenStream2 {
+        let trait_path = self.diff_trait_path();
+
+        match self {
+            Comparison::Decimals => {
+                quote!(#trait_path::aeq_diff_with_policy(#first, #second, #tolerance, policy))
+            }
+            Comparison::SignificantFigures => quote!(
+                #trait_path::aeq_sf_mode_diff_with_policy(#first, #second, #tolerance, mode, policy)
+            ),
+        }
+    }
+
+    /// Signature shared by the generated comparison and diff methods.
+    fn signature(&self, method: &str, output: &TokenStream2) -> TokenStream2 {
+        let method = Ident::new(method, Span::call_site());
+        let tolerance = self.tolerance();
+
+        match self {
+            Comparison::Decimals => quote!(
+                fn #method(
+                    &self,
+                    other: Self,
+                    #tolerance: u8,
+                    policy: &::float_plus::SpecialValuePolicy,
+                ) -> #output
+            ),
+            Comparison::SignificantFigures => quote!(
+                fn #method(
+                    &self,
+                    other: Self,
+                    #tolerance: u8,
+                    mode: ::float_plus::significant_figures::approx_eq_sf::SfMode,
+                    policy: &::float_plus::SpecialValuePolicy,
+                ) -> #output
+            ),
+        }
+    }
+
+    fn eq_method(&self) -> &'static str {
+        match self {
+            Comparison::Decimals => "aeq_with_policy",
+            Comparison::SignificantFigures => "aeq_sf_mode_with_policy",
+        }
+    }
+
+    fn diff_method(&self) -> &'static str {
+        match self {
+            Comparison::Decimals => "aeq_diff_with_policy",
+            Comparison::SignificantFigures => "aeq_sf_mode_diff_with_policy",
+        }
+    }
+}
+
+/// Contents of the `#[approx(..)]` attributes of a field, with the span of each option.
+#[derive(Default)]
+struct FieldAttributes {
+    skip: Option<Span>,
+    exact: Option<Span>,
+    decimals: Option<(u8, Span)>,
+    significant_figures: Option<(u8, Span)>,
+}
+
+impl FieldAttributes {
+    fn parse(attrs: &[syn::Attribute]) -> Result<Self> {
+        let mut attributes = FieldAttributes::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("approx")) {
+            attr.parse_nested_meta(|meta| {
+                let span = meta.path.span();
+
+                if meta.path.is_ident("skip") {
+                    attributes.skip = Some(span);
+                } else if meta.path.is_ident("exact") {
+                    attributes.exact = Some(span);
+                } else if meta.path.is_ident("decimals") {
+                    let value: LitInt = meta.value()?.parse()?;
+                    attributes.decimals = Some((value.base10_parse()?, span));
+                } else if meta.path.is_ident("sf") {
+                    let value: LitInt = meta.value()?.parse()?;
+                    attributes.significant_figures = Some((value.base10_parse()?, span));
+                } else {
+                    return Err(
+                        meta.error("expected `skip`, `exact`, `decimals = ..` or `sf = ..`")
+                    );
+                }
+
+                if attributes.skip.is_some() && attributes.exact.is_some() {
+                    return Err(meta.error("`skip` and `exact` are mutually exclusive"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(attributes)
+    }
+}
+
+/// Field of a struct or variant with the bindings used in the generated patterns.
+struct Field {
+    member: TokenStream2,
+    name: String,
+    first: Ident,
+    second: Ident,
+    attributes: FieldAttributes,
+}
+
+fn fields(fields: &Fields) -> Result<Vec<Field>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let (member, name) = if let Some(ident) = &field.ident {
+                (quote!(#ident), ident.to_string())
+            } else {
+                let index = syn::Index::from(index);
+                (quote!(#index), index.index.to_string())
+            };
+
+            let field = Field {
+                member,
+                name,
+                first: format_ident!("first_{}", index),
+                second: format_ident!("second_{}", index),
+                attributes: FieldAttributes::parse(&field.attrs)?,
+            };
+
+            Ok(field)
+        })
+        .collect()
+}
+
+/// Patterns binding every field of `path` in `self` and in `other`.
+fn patterns(path: &TokenStream2, fields: &[Field]) -> (TokenStream2, TokenStream2) {
+    let members = fields.iter().map(|field| &field.member).collect::<Vec<_>>();
+    let first = fields.iter().map(|field| {
+        if field.attributes.skip.is_some() {
+            format_ident!("_")
+        } else {
+            field.first.clone()
+        }
+    });
+    let second = fields.iter().map(|field| {
+        if field.attributes.skip.is_some() {
+            format_ident!("_")
+        } else {
+            field.second.clone()
+        }
+    });
+
+    (
+        quote!(#path { #(#members: #first),* }),
+        quote!(#path { #(#members: #second),* }),
+    )
+}
+
+/// Whether every compared field of a variant is equal.
+fn eq_body(comparison: &Comparison, fields: &[Field]) -> TokenStream2 {
+    let comparisons = fields
+        .iter()
+        .filter(|field| field.attributes.skip.is_none())
+        .map(|field| {
+            let first = &field.first;
+            let second = &field.second;
+
+            if field.attributes.exact.is_some() {
+                return quote!(#first == &#second);
+            }
+
+            let tolerance = comparison.field_tolerance(&field.attributes);
+            comparison.eq_call(first, second, &tolerance)
+        });
+
+    quote!(true #(&& #comparisons)*)
+}
+
+/// Paths of the mismatching fields of a variant.
+fn diff_body(comparison: &Comparison, fields: &[Field]) -> TokenStream2 {
+    let diffs = fields
+        .iter()
+        .filter(|field| field.attributes.skip.is_none())
+        .map(|field| {
+            let first = &field.first;
+            let second = &field.second;
+            let name = &field.name;
+
+            if field.attributes.exact.is_some() {
+                return quote! {
+                    if #first != &#second {
+                        mismatches.push(::float_plus::alloc::string::String::from(#name));
+                    }
+                };
+            }
+
+            let tolerance = comparison.field_tolerance(&field.attributes);
+            let diff_call = comparison.diff_call(first, second, &tolerance);
+            quote! {
+                mismatches.extend(
+                    #diff_call
+                        .into_iter()
+                        .map(|path| ::float_plus::derive::join_path(#name, &path)),
+                );
+            }
+        });
+
+    quote! {
+        {
+            let mut mismatches = ::float_plus::alloc::vec::Vec::new();
+            #(#diffs)*
+            mismatches
+        }
+    }
+}
+
+/// Assertions that the other trait is derived wherever a field sets its precision, which this
+/// derive would otherwise ignore.
+fn other_precision_checks(
+    input: &DeriveInput,
+    comparison: &Comparison,
+    variants: &[(TokenStream2, Vec<Field>)],
+) -> TokenStream2 {
+    let other = comparison.other();
+    let assertion = match other {
+        Comparison::Decimals => quote!(::float_plus::derive::assert_derives),
+        Comparison::SignificantFigures => quote!(::float_plus::derive::assert_derives_sf),
+    };
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let checks = variants
+        .iter()
+        .flat_map(|(_, fields)| fields)
+        .filter_map(|field| other.precision(&field.attributes))
+        .map(|(_, span)| {
+            let name = Ident::new(&input.ident.to_string(), span);
+
+            quote_spanned! {span=>
+                const _: () = {
+                    #[allow(dead_code)]
+                    fn precision_applies #impl_generics () #where_clause {
+                        #assertion::<#name #ty_generics>();
+                    }
+                };
+            }
+        });
+
+    quote!(#(#checks)*)
+}
+
+/// Adds a bound on `trait_path` to every type parameter.
+fn bounded(generics: &Generics, trait_path: &TokenStream2) -> Generics {
+    let mut generics = generics.clone();
+
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#trait_path));
+    }
+
+    generics
+}
+
+fn expand(input: &DeriveInput, comparison: &Comparison) -> Result<TokenStream2> {
+    let variants = match &input.data {
+        Data::Struct(data) => vec![(quote!(Self), fields(&data.fields)?)],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let ident = &variant.ident;
+                Ok((quote!(Self::#ident), fields(&variant.fields)?))
+            })
+            .collect::<Result<Vec<_>>>()?,
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                input,
+                "approximate equality cannot be derived for unions",
+            ))
+        }
+    };
+
+    let mut eq_arms = Vec::new();
+    let mut diff_arms = Vec::new();
+    for (path, fields) in &variants {
+        let (first, second) = patterns(path, fields);
+        let eq_body = eq_body(comparison, fields);
+        let diff_body = diff_body(comparison, fields);
+
+        eq_arms.push(quote!((#first, #second) => #eq_body,));
+        diff_arms.push(quote!((#first, #second) => #diff_body,));
+    }
+
+    let trait_path = comparison.trait_path();
+    let diff_trait_path = comparison.diff_trait_path();
+    let marker_path = comparison.marker_path();
+    let eq_signature = comparison.signature(comparison.eq_method(), &quote!(bool));
+    let diff_signature = comparison.signature(
+        comparison.diff_method(),
+        &quote!(::float_plus::alloc::vec::Vec<::float_plus::alloc::string::String>),
+    );
+    let precision_checks = other_precision_checks(input, comparison, &variants);
+
+    let name = &input.ident;
+    let generics = bounded(&input.generics, &trait_path);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let diff_generics = bounded(&input.generics, &diff_trait_path);
+    let (diff_impl_generics, _, diff_where_clause) = diff_generics.split_for_impl();
+    let (marker_impl_generics, _, marker_where_clause) = input.generics.split_for_impl();
+
+    let expanded = quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+            #[allow(unused_variables, unreachable_patterns)]
+            #eq_signature {
+                match (self, other) {
+                    #(#eq_arms)*
+                    _ => false,
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #diff_impl_generics #diff_trait_path for #name #ty_generics #diff_where_clause {
+            #[allow(unused_variables, unreachable_patterns)]
+            #diff_signature {
+                match (self, other) {
+                    #(#diff_arms)*
+                    _ => ::float_plus::alloc::vec![::float_plus::alloc::string::String::new()],
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #marker_impl_generics #marker_path for #name #ty_generics #marker_where_clause {}
+
+        #precision_checks
+    };
+
+    Ok(expanded)
+}
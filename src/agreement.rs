@@ -0,0 +1,348 @@
+#[cfg(feature = "num")]
+use num::complex::Complex;
+use num_traits::Float;
+
+#[cfg(feature = "num")]
+use crate::decimals::approx_eq_complex_dec::both_finite;
+use crate::float_like::FloatLike;
+use crate::precision::max_significant_figures;
+use crate::special_values::SpecialValuePolicy;
+use crate::{ApproxEq, ApproxEqSf};
+
+/// How closely two values agree.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Agreement {
+    /// Largest `decimals` for which `aeq` holds, `None` if it fails even at 0 decimals.
+    ///
+    /// Decimals measure the absolute difference, so values below one agree to more decimals
+    /// than significant figures.
+    pub decimals: Option<u8>,
+    /// Largest `significant_figures` for which `aeq_sf` holds, `None` if it fails even at 1.
+    pub significant_figures: Option<u8>,
+    /// `-log10` of the relative error `|a - b| / max(|a|, |b|)`.
+    pub relative_digits: f64,
+}
+
+impl Agreement {
+    /// Agreement of equal values: every precision holds.
+    pub const EXACT: Self = Self {
+        decimals: Some(u8::MAX),
+        significant_figures: Some(u8::MAX),
+        relative_digits: f64::INFINITY,
+    };
+
+    /// Agreement of values that are equal at no precision.
+    pub const NONE: Self = Self {
+        decimals: None,
+        significant_figures: None,
+        relative_digits: f64::NEG_INFINITY,
+    };
+
+    /// Weakest agreement of each measure, for values made of several components.
+    #[cfg(feature = "num")]
+    fn min(self, other: Self) -> Self {
+        Self {
+            decimals: self.decimals.min(other.decimals),
+            significant_figures: self.significant_figures.min(other.significant_figures),
+            relative_digits: self.relative_digits.min(other.relative_digits),
+        }
+    }
+}
+
+/// Largest decimals and significant figures at which `first` and `second` are equal.
+///
+/// `aeq` weakens as decimals decrease, but `aeq_sf` is not monotonic, so every significant figure
+/// count is tried from the largest down.
+fn precisions<T: ApproxEq + ApproxEqSf + Copy>(
+    first: T,
+    second: T,
+    max_significant_figures: u8,
+    policy: SpecialValuePolicy,
+) -> (Option<u8>, Option<u8>) {
+    let decimals = (0..=u8::MAX)
+        .take_while(|decimals| first.aeq_with_policy(second, *decimals, &policy))
+        .last();
+    let significant_figures = (1..=max_significant_figures)
+        .rev()
+        .find(|sf| first.aeq_sf_with_policy(second, *sf, &policy));
+
+    (decimals, significant_figures)
+}
+
+/// `-log10(|first - second| / max(|first|, |second|))` of finite, distinct values.
+fn relative_digits<F: Float>(diff: F, first_abs: F, second_abs: F) -> f64 {
+    let relative_error = diff / first_abs.max(second_abs);
+
+    -relative_error.log10().to_f64().unwrap_or(f64::NAN)
+}
+
+pub trait DigitsOfAgreement: Sized {
+    /// How closely both values agree, treating special values as `policy` specifies.
+    fn digits_of_agreement_with_policy(
+        &self,
+        other: Self,
+        policy: &SpecialValuePolicy,
+    ) -> Agreement;
+
+    /// How closely both values agree.
+    fn digits_of_agreement(&self, other: Self) -> Agreement {
+        self.digits_of_agreement_with_policy(other, &SpecialValuePolicy::default())
+    }
+}
+
+impl<F: FloatLike> DigitsOfAgreement for F {
+    fn digits_of_agreement_with_policy(
+        &self,
+        other: Self,
+        policy: &SpecialValuePolicy,
+    ) -> Agreement {
+        if let Some(equal) = policy.resolve(*self, other) {
+            return if equal {
+                Agreement::EXACT
+            } else {
+                Agreement::NONE
+            };
+        }
+
+        if *self == other {
+            return Agreement::EXACT;
+        }
+
+        let (decimals, significant_figures) =
+            precisions(*self, other, max_significant_figures::<F>(), *policy);
+        let relative_digits = relative_digits((*self - other).abs(), self.abs(), other.abs());
+
+        Agreement {
+            decimals,
+            significant_figures,
+            relative_digits,
+        }
+    }
+}
+
+#[cfg(feature = "num")]
+impl<F: FloatLike> DigitsOfAgreement for Complex<F> {
+    /// Non-finite values agree as their weakest component does.
+    fn digits_of_agreement_with_policy(
+        &self,
+        other: Self,
+        policy: &SpecialValuePolicy,
+    ) -> Agreement {
+        if !both_finite(*self, other) {
+            let re = self.re.digits_of_agreement_with_policy(other.re, policy);
+            let im = self.im.digits_of_agreement_with_policy(other.im, policy);
+
+            return re.min(im);
+        }
+
+        if *self == other {
+            return Agreement::EXACT;
+        }
+
+        let (decimals, significant_figures) =
+            precisions(*self, other, max_significant_figures::<F>(), *policy);
+        let relative_digits = relative_digits((*self - other).norm(), self.norm(), other.norm());
+
+        Agreement {
+            decimals,
+            significant_figures,
+            relative_digits,
+        }
+    }
+}
+
+impl<T: DigitsOfAgreement> DigitsOfAgreement for Option<T> {
+    fn digits_of_agreement_with_policy(
+        &self,
+        other: Self,
+        policy: &SpecialValuePolicy,
+    ) -> Agreement {
+        if self.is_none() && other.is_none() {
+            return if policy.none_equal {
+                Agreement::EXACT
+            } else {
+                Agreement::NONE
+            };
+        }
+
+        if self.is_some() && other.is_some() {
+            let Some(first) = self else {
+                return Agreement::NONE;
+            };
+            let Some(second) = other else {
+                return Agreement::NONE;
+            };
+
+            let agreement = first.digits_of_agreement_with_policy(second, policy);
+
+            return agreement;
+        }
+
+        Agreement::NONE
+    }
+}
+
+impl<T: DigitsOfAgreement, E> DigitsOfAgreement for Result<T, E> {
+    fn digits_of_agreement_with_policy(
+        &self,
+        other: Self,
+        policy: &SpecialValuePolicy,
+    ) -> Agreement {
+        if self.is_err() && other.is_err() {
+            return if policy.err_equal {
+                Agreement::EXACT
+            } else {
+                Agreement::NONE
+            };
+        }
+
+        if self.is_ok() && other.is_ok() {
+            let Ok(first) = self else {
+                return Agreement::NONE;
+            };
+            let Ok(second) = other else {
+                return Agreement::NONE;
+            };
+
+            let agreement = first.digits_of_agreement_with_policy(second, policy);
+
+            return agreement;
+        }
+
+        Agreement::NONE
+    }
+}
+
+impl<'a, T> DigitsOfAgreement for &'a Option<T>
+where
+    &'a T: DigitsOfAgreement,
+{
+    fn digits_of_agreement_with_policy(
+        &self,
+        other: Self,
+        policy: &SpecialValuePolicy,
+    ) -> Agreement {
+        Option::as_ref(*self).digits_of_agreement_with_policy(other.as_ref(), policy)
+    }
+}
+
+impl<'a, T, E> DigitsOfAgreement for &'a Result<T, E>
+where
+    &'a T: DigitsOfAgreement,
+{
+    fn digits_of_agreement_with_policy(
+        &self,
+        other: Self,
+        policy: &SpecialValuePolicy,
+    ) -> Agreement {
+        Result::as_ref(*self).digits_of_agreement_with_policy(other.as_ref(), policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+
+    use super::{Agreement, DigitsOfAgreement};
+    use crate::special_values::SpecialValuePolicy;
+    use crate::{ApproxEq, ApproxEqSf};
+
+    #[test]
+    fn digits_of_agreement_f64() {
+        let a = 1.234_59_f64;
+        let b = 1.234_2_f64;
+        let agreement = a.digits_of_agreement(b);
+
+        assert_eq!(agreement.decimals, Some(3));
+        assert!(a.aeq(b, 3) && !a.aeq(b, 4));
+        assert_eq!(agreement.significant_figures, Some(3));
+        assert!(a.aeq_sf(b, 3) && !a.aeq_sf(b, 4));
+        assert!((agreement.relative_digits - 3.500).abs() < 1e-3);
+
+        let agreement = 1.0_f64.digits_of_agreement(3.0);
+        assert_eq!(agreement.decimals, None);
+        assert_eq!(agreement.significant_figures, None);
+
+        assert_eq!(2.5_f64.digits_of_agreement(2.5), Agreement::EXACT);
+    }
+
+    #[test]
+    fn digits_of_agreement_below_one_and_negative() {
+        let agreement = 0.001_234_f64.digits_of_agreement(0.001_299);
+        assert_eq!(agreement.significant_figures, Some(1));
+        assert_eq!(agreement.decimals, Some(4));
+        assert!((agreement.relative_digits - 1.30).abs() < 1e-2);
+
+        let agreement = (-123.4_f64).digits_of_agreement(-123.2);
+        assert_eq!(agreement.significant_figures, Some(3));
+        assert_eq!(agreement.decimals, Some(0));
+
+        let agreement = (-0.012_34_f32).digits_of_agreement(-0.012_32);
+        assert_eq!(agreement.significant_figures, Some(3));
+        assert_eq!(agreement.decimals, Some(4));
+    }
+
+    #[test]
+    fn digits_of_agreement_f32() {
+        let agreement = 1_000.0_f32.digits_of_agreement(1_000.06);
+        assert_eq!(agreement.decimals, Some(1));
+        assert_eq!(agreement.significant_figures, Some(4));
+        assert!((agreement.relative_digits - 4.22).abs() < 1e-2);
+
+        // Adjacent floats agree to every representable figure.
+        let next = f32::from_bits(1.0_f32.to_bits() + 1);
+        assert_eq!(
+            1.0_f32.digits_of_agreement(next).significant_figures,
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn digits_of_agreement_special_values() {
+        assert_eq!(f64::NAN.digits_of_agreement(f64::NAN), Agreement::NONE);
+        assert_eq!(f64::INFINITY.digits_of_agreement(1.0), Agreement::NONE);
+        assert_eq!(
+            f64::INFINITY.digits_of_agreement(f64::INFINITY),
+            Agreement::EXACT
+        );
+
+        let policy = SpecialValuePolicy {
+            nan_equal: true,
+            ..SpecialValuePolicy::default()
+        };
+        assert_eq!(
+            f64::NAN.digits_of_agreement_with_policy(f64::NAN, &policy),
+            Agreement::EXACT
+        );
+    }
+
+    #[test]
+    fn digits_of_agreement_option_result() {
+        assert_eq!(
+            Some(1.0_f64).digits_of_agreement(Some(1.01)).decimals,
+            Some(1)
+        );
+        assert_eq!(None::<f64>.digits_of_agreement(None), Agreement::EXACT);
+        assert_eq!(Some(1.0_f64).digits_of_agreement(None), Agreement::NONE);
+
+        let a = Ok::<f32, Error>(1.0);
+        let b = Err(Error::msg(""));
+        assert_eq!(a.digits_of_agreement(b), Agreement::NONE);
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn digits_of_agreement_complex() {
+        use num::complex::Complex64;
+
+        let a = Complex64::new(1.0, 1.0);
+        let b = Complex64::new(1.000_8, 1.000_8);
+        let agreement = a.digits_of_agreement(b);
+        assert_eq!(agreement.decimals, Some(3));
+        assert!((agreement.relative_digits - 3.097).abs() < 1e-3);
+
+        let c = Complex64::new(f64::NAN, 1.0);
+        assert_eq!(c.digits_of_agreement(a).decimals, None);
+        assert_eq!(a.digits_of_agreement(a), Agreement::EXACT);
+    }
+}
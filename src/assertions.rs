@@ -0,0 +1,685 @@
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use core::fmt::{Debug, Write};
+use core::mem::size_of;
+
+#[cfg(feature = "num")]
+use num::complex::Complex;
+
+use crate::float_like::FloatLike;
+use crate::precision::max_significant_figures;
+use crate::{ulps_between, DigitsOfAgreement};
+
+/// Snapshot of a compared value, taken before the comparison consumes it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operand {
+    F32(f32),
+    F64(f64),
+    Complex32(f32, f32),
+    Complex64(f64, f64),
+    None,
+    Err,
+}
+
+pub trait AssertOperand {
+    /// Snapshot of `self` for an assertion failure message.
+    fn operand(&self) -> Operand;
+}
+
+impl<F: FloatLike> AssertOperand for F {
+    /// Single-precision types are snapshotted as `f32`, so their ulps are counted at that
+    /// precision; everything else is widened to `f64`.
+    fn operand(&self) -> Operand {
+        if size_of::<F>() <= size_of::<f32>() {
+            return Operand::F32(self.to_f32().unwrap_or(f32::NAN));
+        }
+
+        Operand::F64(self.to_f64().unwrap_or(f64::NAN))
+    }
+}
+
+#[cfg(feature = "num")]
+impl<F: FloatLike> AssertOperand for Complex<F> {
+    /// Snapshotted at the precision of the components, like the scalar impl.
+    fn operand(&self) -> Operand {
+        if let (Operand::F32(re), Operand::F32(im)) = (self.re.operand(), self.im.operand()) {
+            return Operand::Complex32(re, im);
+        }
+
+        let re = self.re.to_f64().unwrap_or(f64::NAN);
+        let im = self.im.to_f64().unwrap_or(f64::NAN);
+
+        Operand::Complex64(re, im)
+    }
+}
+
+impl<T: AssertOperand> AssertOperand for Option<T> {
+    fn operand(&self) -> Operand {
+        let Some(value) = self else {
+            return Operand::None;
+        };
+
+        value.operand()
+    }
+}
+
+impl<T: AssertOperand, E> AssertOperand for Result<T, E> {
+    fn operand(&self) -> Operand {
+        let Ok(value) = self else {
+            return Operand::Err;
+        };
+
+        value.operand()
+    }
+}
+
+impl<T: AssertOperand> AssertOperand for &Option<T> {
+    fn operand(&self) -> Operand {
+        (**self).operand()
+    }
+}
+
+impl<T: AssertOperand, E> AssertOperand for &Result<T, E> {
+    fn operand(&self) -> Operand {
+        (**self).operand()
+    }
+}
+
+/// Snapshot of either side of an assertion: a number, or the `Debug` text of any other value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Snapshot {
+    Operand(Operand),
+    Debug(String),
+}
+
+/// Reference to an operand, snapshotted through `AssertOperand` when the operand implements it
+/// and through `Debug` otherwise.
+#[doc(hidden)]
+pub struct Capture<'a, T>(pub &'a T);
+
+#[doc(hidden)]
+pub trait CaptureOperand {
+    fn capture(&self) -> Snapshot;
+}
+
+impl<T: AssertOperand> CaptureOperand for Capture<'_, T> {
+    fn capture(&self) -> Snapshot {
+        Snapshot::Operand(self.0.operand())
+    }
+}
+
+#[doc(hidden)]
+pub trait CaptureDebug {
+    fn capture(&self) -> Snapshot;
+}
+
+impl<T: Debug> CaptureDebug for &Capture<'_, T> {
+    fn capture(&self) -> Snapshot {
+        Snapshot::Debug(format!("{:?}", self.0))
+    }
+}
+
+/// Panic message for a failed approximate assertion on any operands.
+///
+/// Numeric operands get the differences of `failure_message`; any other operand is listed by its
+/// `Debug` text.
+pub fn snapshot_failure_message(
+    assertion: &str,
+    left: Snapshot,
+    right: Snapshot,
+    tolerance: &str,
+) -> String {
+    let (left, right) = match (left, right) {
+        (Snapshot::Operand(left), Snapshot::Operand(right)) => {
+            return failure_message(assertion, left, right, tolerance);
+        }
+        (left, right) => (left, right),
+    };
+
+    let mut message = format!("assertion `left {assertion} right` failed\n");
+    let _ = writeln!(message, "       left: {}", describe_snapshot(left));
+    let _ = writeln!(message, "      right: {}", describe_snapshot(right));
+    let _ = write!(message, "  tolerance: {tolerance}");
+
+    message
+}
+
+fn describe_snapshot(snapshot: Snapshot) -> String {
+    match snapshot {
+        Snapshot::Operand(operand) => describe(operand),
+        Snapshot::Debug(text) => text,
+    }
+}
+
+/// Panic message for a failed approximate assertion.
+///
+/// Lists both operands at full precision and, when both are numbers, their absolute, relative
+/// and ulps difference and the number of significant figures they agree to.
+pub fn failure_message(assertion: &str, left: Operand, right: Operand, tolerance: &str) -> String {
+    let mut message = format!("assertion `left {assertion} right` failed\n");
+    let _ = writeln!(message, "       left: {}", describe(left));
+    let _ = writeln!(message, "      right: {}", describe(right));
+    let _ = write!(message, "  tolerance: {tolerance}");
+
+    let missing = [("left", left), ("right", right)]
+        .into_iter()
+        .filter_map(|(side, operand)| match operand {
+            Operand::None => Some(format!("\n       note: {side} is None")),
+            Operand::Err => Some(format!("\n       note: {side} is Err")),
+            _ => None,
+        })
+        .collect::<String>();
+    if !missing.is_empty() {
+        message.push_str(&missing);
+        return message;
+    }
+
+    let (abs_diff, magnitude) = if let (Some(left), Some(right)) = (complex(left), complex(right)) {
+        let abs_diff = (left.0 - right.0).hypot(left.1 - right.1);
+        let magnitude = left.0.hypot(left.1).max(right.0.hypot(right.1));
+        (abs_diff, magnitude)
+    } else {
+        let left = real(left);
+        let right = real(right);
+        ((left - right).abs(), left.abs().max(right.abs()))
+    };
+    let _ = write!(message, "\n   abs diff: {abs_diff:e}");
+    let _ = write!(message, "\n   rel diff: {:e}", abs_diff / magnitude);
+
+    let (ulps, agreeing_sf) = match (left, right) {
+        (Operand::F32(left), Operand::F32(right)) => {
+            (ulps_between(left, right), agreeing_sf(left, right))
+        }
+        (Operand::Complex32(left_re, left_im), Operand::Complex32(right_re, right_im)) => {
+            complex_diffs((left_re, left_im), (right_re, right_im))
+        }
+        (Operand::Complex64(left_re, left_im), Operand::Complex64(right_re, right_im)) => {
+            complex_diffs((left_re, left_im), (right_re, right_im))
+        }
+        _ => {
+            let left = real(left);
+            let right = real(right);
+            (ulps_between(left, right), agreeing_sf(left, right))
+        }
+    };
+    let _ = write!(message, "\n  ulps diff: {ulps}");
+    let _ = write!(message, "\nagreeing sf: {agreeing_sf}");
+
+    message
+}
+
+fn describe(operand: Operand) -> String {
+    match operand {
+        Operand::F32(value) => format!("{value:?}"),
+        Operand::F64(value) => format!("{value:?}"),
+        Operand::Complex32(re, im) => format!("{re:?} + {im:?}i"),
+        Operand::Complex64(re, im) => format!("{re:?} + {im:?}i"),
+        Operand::None => "None".to_owned(),
+        Operand::Err => "Err(..)".to_owned(),
+    }
+}
+
+fn real(operand: Operand) -> f64 {
+    match operand {
+        Operand::F32(value) => f64::from(value),
+        Operand::F64(value) => value,
+        _ => f64::NAN,
+    }
+}
+
+/// Components of a complex operand, widened to `f64`.
+fn complex(operand: Operand) -> Option<(f64, f64)> {
+    match operand {
+        Operand::Complex32(re, im) => Some((f64::from(re), f64::from(im))),
+        Operand::Complex64(re, im) => Some((re, im)),
+        _ => None,
+    }
+}
+
+/// Significant figures both values agree to, at most as many as `F` holds.
+fn agreeing_sf<F: FloatLike>(left: F, right: F) -> u8 {
+    left.digits_of_agreement(right)
+        .significant_figures
+        .map_or(0, |significant_figures| {
+            significant_figures.min(max_significant_figures::<F>())
+        })
+}
+
+/// Ulps diff and agreeing significant figures of the weaker component, counted in `F`.
+fn complex_diffs<F: FloatLike>(left: (F, F), right: (F, F)) -> (u64, u8) {
+    let ulps = ulps_between(left.0, right.0).max(ulps_between(left.1, right.1));
+    let agreeing_sf = agreeing_sf(left.0, right.0).min(agreeing_sf(left.1, right.1));
+
+    (ulps, agreeing_sf)
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_approx {
+    (@by_ref $compare:path, $assertion:literal, $name:literal, $left:expr, $right:expr, $tolerance:expr $(, $($arg:tt)+)?) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                if !$compare(left, right, tolerance) {
+                    #[allow(unused_imports)]
+                    use $crate::assertions::{CaptureDebug as _, CaptureOperand as _};
+                    let message = $crate::assertions::snapshot_failure_message(
+                        $assertion,
+                        (&$crate::assertions::Capture(left)).capture(),
+                        (&$crate::assertions::Capture(right)).capture(),
+                        &$crate::alloc::format!("{} = {:?}", $name, tolerance),
+                    );
+                    $(let message = $crate::alloc::format!("{}\n    context: {}", message, ::core::format_args!($($arg)+));)?
+                    ::core::panic!("{}", message);
+                }
+            }
+        }
+    };
+    ($compare:path, $assertion:literal, $name:literal, $left:expr, $right:expr, $tolerance:expr $(, $($arg:tt)+)?) => {
+        match ($left, $right, $tolerance) {
+            (left, right, tolerance) => {
+                #[allow(unused_imports)]
+                use $crate::assertions::{CaptureDebug as _, CaptureOperand as _};
+                // `right` is consumed by the comparison, so it is snapshotted first; numbers are
+                // snapshotted without allocating.
+                let right_snapshot = (&$crate::assertions::Capture(&right)).capture();
+                if !$compare(&left, right, tolerance) {
+                    let message = $crate::assertions::snapshot_failure_message(
+                        $assertion,
+                        (&$crate::assertions::Capture(&left)).capture(),
+                        right_snapshot,
+                        &$crate::alloc::format!("{} = {:?}", $name, tolerance),
+                    );
+                    $(let message = $crate::alloc::format!("{}\n    context: {}", message, ::core::format_args!($($arg)+));)?
+                    ::core::panic!("{}", message);
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that `ApproxEq::aeq` holds, printing both values and their differences otherwise.
+#[macro_export]
+macro_rules! assert_aeq {
+    ($left:expr, $right:expr, $decimals:expr $(, $($arg:tt)+)?) => {
+        $crate::__assert_approx!($crate::ApproxEq::aeq, "aeq", "decimals", $left, $right, $decimals $(, $($arg)+)?)
+    };
+}
+
+/// Asserts that `NApproxEq::nae` holds, printing both values and their differences otherwise.
+#[macro_export]
+macro_rules! assert_nae {
+    ($left:expr, $right:expr, $decimals:expr $(, $($arg:tt)+)?) => {
+        $crate::__assert_approx!($crate::NApproxEq::nae, "nae", "decimals", $left, $right, $decimals $(, $($arg)+)?)
+    };
+}
+
+/// Asserts that `ApproxEqSf::aeq_sf` holds, printing both values and their differences otherwise.
+#[macro_export]
+macro_rules! assert_aeq_sf {
+    ($left:expr, $right:expr, $significant_figures:expr $(, $($arg:tt)+)?) => {
+        $crate::__assert_approx!($crate::ApproxEqSf::aeq_sf, "aeq_sf", "significant_figures", $left, $right, $significant_figures $(, $($arg)+)?)
+    };
+}
+
+/// Asserts that `NApproxEqSf::nae_sf` holds, printing both values and their differences otherwise.
+#[macro_export]
+macro_rules! assert_nae_sf {
+    ($left:expr, $right:expr, $significant_figures:expr $(, $($arg:tt)+)?) => {
+        $crate::__assert_approx!($crate::significant_figures::not_approx_eq_sf::NApproxEqSf::nae_sf, "nae_sf", "significant_figures", $left, $right, $significant_figures $(, $($arg)+)?)
+    };
+}
+
+/// Asserts that `ApproxEqRounded::aeq_rounded` holds, printing both values and their differences
+/// otherwise.
+#[macro_export]
+macro_rules! assert_aeq_rounded {
+    ($left:expr, $right:expr, $decimals:expr $(, $($arg:tt)+)?) => {
+        $crate::__assert_approx!($crate::ApproxEqRounded::aeq_rounded, "aeq_rounded", "decimals", $left, $right, $decimals $(, $($arg)+)?)
+    };
+}
+
+/// Asserts that `ApproxEqRel::aeq_rel` holds, printing both values and their differences otherwise.
+#[macro_export]
+macro_rules! assert_aeq_rel {
+    ($left:expr, $right:expr, $rel_tol:expr $(, $($arg:tt)+)?) => {
+        $crate::__assert_approx!($crate::ApproxEqRel::aeq_rel, "aeq_rel", "rel_tol", $left, $right, $rel_tol $(, $($arg)+)?)
+    };
+}
+
+/// Asserts that `ApproxEqUlps::aeq_ulps` holds, printing both values and their differences
+/// otherwise.
+#[macro_export]
+macro_rules! assert_aeq_ulps {
+    ($left:expr, $right:expr, $max_ulps:expr $(, $($arg:tt)+)?) => {
+        $crate::__assert_approx!($crate::ApproxEqUlps::aeq_ulps, "aeq_ulps", "max_ulps", $left, $right, $max_ulps $(, $($arg)+)?)
+    };
+}
+
+/// Asserts that `IsClose::is_close` holds for `&IsCloseTolerance`, printing both values and their
+/// differences otherwise.
+#[macro_export]
+macro_rules! assert_is_close {
+    ($left:expr, $right:expr, $tolerance:expr $(, $($arg:tt)+)?) => {
+        $crate::__assert_approx!($crate::IsClose::is_close, "is_close", "tolerance", $left, $right, $tolerance $(, $($arg)+)?)
+    };
+}
+
+/// Asserts that `ApproxEqWith::approx_eq` holds, printing both values and their differences
+/// otherwise.
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr, $tolerance:expr $(, $($arg:tt)+)?) => {
+        $crate::__assert_approx!(@by_ref $crate::ApproxEqWith::approx_eq, "approx_eq", "tolerance", $left, $right, $tolerance $(, $($arg)+)?)
+    };
+}
+
+/// Asserts that `ApproxEqWith::approx_ne` holds, printing both values and their differences
+/// otherwise.
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($left:expr, $right:expr, $tolerance:expr $(, $($arg:tt)+)?) => {
+        $crate::__assert_approx!(@by_ref $crate::ApproxEqWith::approx_ne, "approx_ne", "tolerance", $left, $right, $tolerance $(, $($arg)+)?)
+    };
+}
+
+/// Debug-only `assert_aeq!`.
+#[macro_export]
+macro_rules! debug_assert_aeq {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_aeq!($($arg)*);
+        }
+    };
+}
+
+/// Debug-only `assert_nae!`.
+#[macro_export]
+macro_rules! debug_assert_nae {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_nae!($($arg)*);
+        }
+    };
+}
+
+/// Debug-only `assert_aeq_sf!`.
+#[macro_export]
+macro_rules! debug_assert_aeq_sf {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_aeq_sf!($($arg)*);
+        }
+    };
+}
+
+/// Debug-only `assert_nae_sf!`.
+#[macro_export]
+macro_rules! debug_assert_nae_sf {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_nae_sf!($($arg)*);
+        }
+    };
+}
+
+/// Debug-only `assert_aeq_rounded!`.
+#[macro_export]
+macro_rules! debug_assert_aeq_rounded {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_aeq_rounded!($($arg)*);
+        }
+    };
+}
+
+/// Debug-only `assert_aeq_rel!`.
+#[macro_export]
+macro_rules! debug_assert_aeq_rel {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_aeq_rel!($($arg)*);
+        }
+    };
+}
+
+/// Debug-only `assert_aeq_ulps!`.
+#[macro_export]
+macro_rules! debug_assert_aeq_ulps {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_aeq_ulps!($($arg)*);
+        }
+    };
+}
+
+/// Debug-only `assert_is_close!`.
+#[macro_export]
+macro_rules! debug_assert_is_close {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_is_close!($($arg)*);
+        }
+    };
+}
+
+/// Debug-only `assert_approx_eq!`.
+#[macro_export]
+macro_rules! debug_assert_approx_eq {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_approx_eq!($($arg)*);
+        }
+    };
+}
+
+/// Debug-only `assert_approx_ne!`.
+#[macro_export]
+macro_rules! debug_assert_approx_ne {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_approx_ne!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+
+    use super::{failure_message, snapshot_failure_message, AssertOperand, Operand, Snapshot};
+    use crate::relative::is_close::IsCloseTolerance;
+    use crate::special_values::SpecialValuePolicy;
+    use crate::{ApproxEq, Tolerance};
+
+    /// A value without an `AssertOperand` impl.
+    #[derive(Debug)]
+    struct Point(f64, f64);
+
+    impl ApproxEq for Point {
+        fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
+            self.0.aeq_with_policy(other.0, decimals, policy)
+                && self.1.aeq_with_policy(other.1, decimals, policy)
+        }
+    }
+
+    #[test]
+    fn passing_assertions() {
+        assert_aeq!(100.123_456_789_f64, 100.123_456_712, 7);
+        assert_nae!(100.123_456_789_f64, 100.123_456_712, 8);
+        assert_aeq_sf!(Some(100.456_7_f32), Some(100.5), 4);
+        assert_nae_sf!(Some(100.456_7_f32), None, 4);
+        assert_aeq_rounded!(1.235_1_f64, 1.235_4, 3);
+        assert_aeq_rel!(1e12_f64, 1e12 + 1.0, 1e-9, "case {}", 1);
+        assert_aeq_ulps!(0.1_f64 + 0.2, 0.3, 1);
+        assert_is_close!(1.0_f64, 1.0 + 1e-10, &IsCloseTolerance::python());
+        debug_assert_aeq!(Ok::<f64, Error>(1.0), Ok(1.0), 3);
+        debug_assert_nae!(1.0_f64, 2.0, 3);
+        debug_assert_aeq_sf!(1.0_f64, 1.0, 3);
+        debug_assert_nae_sf!(1.0_f64, 2.0, 3);
+        debug_assert_aeq_rounded!(1.0_f64, 1.0, 3);
+        debug_assert_aeq_rel!(1.0_f64, 1.0, 0.0);
+        debug_assert_aeq_ulps!(1.0_f64, 1.0, 0);
+        debug_assert_is_close!(1.0_f64, 1.0, &IsCloseTolerance::numpy());
+        assert_approx_eq!(Some(1e-12_f64), Some(2e-12), Tolerance::Abs(1e-11));
+        assert_approx_ne!(1e-12_f64, 2e-12, Tolerance::Rel(1e-9));
+        debug_assert_approx_eq!(1.0_f64, 1.0, Tolerance::Ulps(0));
+        debug_assert_approx_ne!(1.0_f64, 2.0, Tolerance::SigFigs(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion `left aeq right` failed")]
+    fn failing_assertion_panics() {
+        assert_aeq!(100.123_456_789_f64, 100.123_456_712, 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "tolerance: tolerance = SigFigs(3)")]
+    fn failing_by_ref_assertion_panics() {
+        assert_approx_eq!(1.0_f64, 2.0, Tolerance::SigFigs(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "context: row 3")]
+    fn failing_assertion_with_context() {
+        assert_aeq_sf!(1.0_f64, 2.0, 3, "row {}", 3);
+    }
+
+    #[test]
+    fn assertions_on_debug_operands() {
+        assert_aeq!(Point(1.0, 2.0), Point(1.000_1, 2.0), 3);
+        debug_assert_nae!(Point(1.0, 2.0), Point(1.0, 2.1), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "left: Point(1.0, 2.0)\n      right: Point(1.0, 2.1)")]
+    fn failing_assertion_on_debug_operands_panics() {
+        assert_aeq!(Point(1.0, 2.0), Point(1.0, 2.1), 3);
+    }
+
+    #[test]
+    fn assertions_on_collections() {
+        assert_aeq!(vec![1.0_f64, 2.0], vec![1.000_1, 2.0], 3);
+        assert_aeq_sf!(vec![Some(100.456_7_f32)], vec![Some(100.5)], 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "left: (1.0, 2.0)\n      right: (1.0, 2.1)")]
+    fn failing_assertion_on_tuples_panics() {
+        assert_aeq!((1.0_f64, 2.0_f32), (1.000_1, 2.0), 3);
+        assert_aeq!((1.0_f64, 2.0_f32), (1.0, 2.1), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "left: [1.0, 2.0]\n      right: [1.0, 2.1]")]
+    fn failing_assertion_on_collections_panics() {
+        assert_aeq!(vec![1.0_f64, 2.0], vec![1.0, 2.1], 3);
+    }
+
+    #[derive(Debug, float_plus_derive::ApproxEq, float_plus_derive::ApproxEqSf)]
+    struct Position {
+        x: f64,
+        y: f64,
+    }
+
+    #[test]
+    fn assertions_on_derived_structs() {
+        assert_aeq!(
+            Position { x: 1.0, y: 2.0 },
+            Position { x: 1.000_1, y: 2.0 },
+            3
+        );
+        assert_nae_sf!(Position { x: 1.0, y: 2.0 }, Position { x: 1.0, y: 2.1 }, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "right: Position { x: 1.0, y: 2.1 }")]
+    fn failing_assertion_on_derived_structs_panics() {
+        assert_aeq!(Position { x: 1.0, y: 2.0 }, Position { x: 1.0, y: 2.1 }, 3);
+    }
+
+    #[test]
+    fn snapshot_failure_message_mixes_operands() {
+        let message = snapshot_failure_message(
+            "aeq",
+            Snapshot::Operand(Operand::F64(1.0)),
+            Snapshot::Debug("[1.0]".to_owned()),
+            "decimals = 3",
+        );
+        assert_eq!(
+            message,
+            "assertion `left aeq right` failed\n       left: 1.0\n      right: [1.0]\n  \
+             tolerance: decimals = 3"
+        );
+
+        let left = Snapshot::Operand(1.0_f64.operand());
+        let right = Snapshot::Operand(1.5_f64.operand());
+        assert_eq!(
+            snapshot_failure_message("aeq", left, right, "decimals = 3"),
+            failure_message("aeq", 1.0_f64.operand(), 1.5_f64.operand(), "decimals = 3")
+        );
+    }
+
+    #[test]
+    fn failure_message_reports_differences() {
+        let message = failure_message("aeq", 1.0_f64.operand(), 1.5_f64.operand(), "decimals = 3");
+        assert_eq!(
+            message,
+            "assertion `left aeq right` failed\n       left: 1.0\n      right: 1.5\n  tolerance: \
+             decimals = 3\n   abs diff: 5e-1\n   rel diff: 3.333333333333333e-1\n  ulps diff: \
+             2251799813685248\nagreeing sf: 0"
+        );
+
+        let message = failure_message(
+            "aeq_sf",
+            Operand::F32(100.456_7),
+            Operand::F32(100.5),
+            "significant_figures = 5",
+        );
+        assert!(message.contains("left: 100.4567"));
+        assert!(message.contains("agreeing sf: 4"));
+    }
+
+    #[test]
+    fn failure_message_counts_ulps_at_operand_precision() {
+        let message = failure_message("aeq", 1.0_f64.operand(), 1.0_f64.operand(), "decimals = 3");
+        assert!(message.contains("agreeing sf: 17"));
+
+        let left = Operand::Complex32(1.0, 2.0);
+        let right = Operand::Complex32(1.0 + f32::EPSILON, 2.0);
+        let message = failure_message("aeq", left, right, "decimals = 9");
+        assert!(message.contains("left: 1.0 + 2.0i"));
+        assert!(message.contains("ulps diff: 1\n"));
+        assert!(message.contains("agreeing sf: 7"));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn complex_operand_keeps_precision() {
+        use num::complex::{Complex32, Complex64};
+
+        assert_eq!(
+            Complex32::new(1.0, 2.0).operand(),
+            Operand::Complex32(1.0, 2.0)
+        );
+        assert_eq!(
+            Complex64::new(1.0, 2.0).operand(),
+            Operand::Complex64(1.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn failure_message_names_missing_side() {
+        let left = Some(1.0_f64).operand();
+        let right = None::<f64>.operand();
+        let message = failure_message("aeq", left, right, "decimals = 3");
+        assert!(message.contains("right: None"));
+        assert!(message.contains("note: right is None"));
+        assert!(!message.contains("abs diff"));
+
+        let left = Err::<f64, Error>(Error::msg("")).operand();
+        let right = Ok::<f64, Error>(1.0).operand();
+        let message = failure_message("aeq", left, right, "decimals = 3");
+        assert!(message.contains("note: left is Err"));
+    }
+}
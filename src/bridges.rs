@@ -0,0 +1,28 @@
+#[cfg(feature = "approx")]
+pub mod approx_bridge;
+#[cfg(feature = "float_cmp")]
+pub mod float_cmp_bridge;
+
+use crate::tolerance::Tolerance;
+
+/// Value compared to `D` decimals by the `approx` and `float-cmp` traits.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Decimals<T, const D: u8>(pub T);
+
+/// Value compared to `S` significant figures by the `approx` and `float-cmp` traits.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct SigFigs<T, const S: u8>(pub T);
+
+impl<T, const D: u8> Decimals<T, D> {
+    /// Tolerance carried by the type.
+    pub fn tolerance() -> Tolerance {
+        Tolerance::Decimals(D)
+    }
+}
+
+impl<T, const S: u8> SigFigs<T, S> {
+    /// Tolerance carried by the type.
+    pub fn tolerance() -> Tolerance {
+        Tolerance::SigFigs(S)
+    }
+}
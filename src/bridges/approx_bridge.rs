@@ -0,0 +1,276 @@
+use alloc::vec;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num_traits::{Float, Zero};
+
+use super::{Decimals, SigFigs};
+use crate::significant_figures::approx_eq_sf::SfMode;
+use crate::special_values::SpecialValuePolicy;
+use crate::tolerance::Tolerance;
+use crate::{ApproxEq, ApproxEqSf, ApproxEqUlps, ApproxEqWith};
+
+/// `approx` traits for a wrapper carrying a tolerance, with `Tolerance` as the epsilon.
+///
+/// `abs_diff_eq` compares within the epsilon, `relative_eq` within the epsilon or
+/// `max_relative`, and `ulps_eq` within the epsilon or `max_ulps`. The defaults are the
+/// wrapper's tolerance and 0 ulps.
+macro_rules! tolerance_wrapper_impls {
+    ($wrapper:ident, $precision:ident) => {
+        impl<T, const $precision: u8> AbsDiffEq for $wrapper<T, $precision>
+        where
+            T: ApproxEqWith + PartialEq,
+        {
+            type Epsilon = Tolerance;
+
+            fn default_epsilon() -> Tolerance {
+                Self::tolerance()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Tolerance) -> bool {
+                self.0.approx_eq(&other.0, &epsilon)
+            }
+        }
+
+        impl<T, const $precision: u8> RelativeEq for $wrapper<T, $precision>
+        where
+            T: ApproxEqWith + PartialEq,
+        {
+            fn default_max_relative() -> Tolerance {
+                Self::tolerance()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Tolerance,
+                max_relative: Tolerance,
+            ) -> bool {
+                self.0
+                    .approx_eq(&other.0, &Tolerance::Any(vec![epsilon, max_relative]))
+            }
+        }
+
+        impl<T, const $precision: u8> UlpsEq for $wrapper<T, $precision>
+        where
+            T: ApproxEqWith + PartialEq,
+        {
+            fn default_max_ulps() -> u32 {
+                0
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Tolerance, max_ulps: u32) -> bool {
+                let tolerance = Tolerance::Any(vec![epsilon, Tolerance::Ulps(max_ulps)]);
+                self.0.approx_eq(&other.0, &tolerance)
+            }
+        }
+    };
+}
+
+tolerance_wrapper_impls!(Decimals, D);
+tolerance_wrapper_impls!(SigFigs, S);
+
+/// `5` and `10` built from `one()`, since an `approx` epsilon need not convert from a primitive.
+fn five_and_ten<F: Float>() -> (F, F) {
+    let two = F::one() + F::one();
+    let five = two + two + F::one();
+
+    (five, five * two)
+}
+
+/// Adapter giving any `approx` type (`nalgebra`, `cgmath`, `glam`, ..) this crate's methods.
+///
+/// Special values follow the `approx` impl of the wrapped type, so the policy is ignored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Approx<T>(pub T);
+
+impl<T> ApproxEq for Approx<T>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Float,
+{
+    /// Whether `abs_diff_eq` holds with an epsilon of `10^-decimals`.
+    fn aeq_with_policy(&self, other: Self, decimals: u8, _policy: &SpecialValuePolicy) -> bool {
+        let (_, ten) = five_and_ten::<T::Epsilon>();
+        let epsilon = ten.powi(-i32::from(decimals));
+
+        let aeq = self.0.abs_diff_eq(&other.0, epsilon);
+
+        aeq
+    }
+}
+
+impl<T> ApproxEqSf for Approx<T>
+where
+    T: RelativeEq,
+    T::Epsilon: Float,
+{
+    /// Whether `relative_eq` holds with `max_relative` of half a unit in the last significant
+    /// figure, `5 * 10^-significant_figures`.
+    ///
+    /// `approx` exposes neither rounding nor the magnitude of a value, so `SfMode::Rounded` is
+    /// approximated by this relative comparison and no `SfMode::Distance` can be honoured.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mode` is `SfMode::Distance`.
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        _policy: &SpecialValuePolicy,
+    ) -> bool {
+        assert!(
+            mode == SfMode::Rounded,
+            "`Approx` only supports `SfMode::Rounded`, got {mode:?}"
+        );
+
+        let (five, ten) = five_and_ten::<T::Epsilon>();
+        let max_relative = five * ten.powi(-i32::from(significant_figures));
+
+        let aeq_sf = self
+            .0
+            .relative_eq(&other.0, <T::Epsilon as Zero>::zero(), max_relative);
+
+        aeq_sf
+    }
+}
+
+impl<T> ApproxEqUlps for Approx<T>
+where
+    T: UlpsEq,
+    T::Epsilon: Float,
+{
+    /// Whether `ulps_eq` holds with a zero epsilon.
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        _policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_ulps = self
+            .0
+            .ulps_eq(&other.0, <T::Epsilon as Zero>::zero(), max_ulps);
+
+        aeq_ulps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::{
+        abs_diff_eq, assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq, relative_eq,
+    };
+
+    use super::Approx;
+    use crate::bridges::{Decimals, SigFigs};
+    use crate::relative::approx_eq_rel::Reference;
+    use crate::significant_figures::approx_eq_sf::SfMode;
+    use crate::tolerance::Tolerance;
+    use crate::{ApproxEq, ApproxEqSf, ApproxEqUlps, NApproxEq};
+
+    /// Stand-in for a vector type from a linear algebra crate.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Vec2(f64, f64);
+
+    impl approx::AbsDiffEq for Vec2 {
+        type Epsilon = f64;
+
+        fn default_epsilon() -> f64 {
+            f64::EPSILON
+        }
+
+        fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+            self.0.abs_diff_eq(&other.0, epsilon) && self.1.abs_diff_eq(&other.1, epsilon)
+        }
+    }
+
+    impl approx::RelativeEq for Vec2 {
+        fn default_max_relative() -> f64 {
+            f64::EPSILON
+        }
+
+        fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+            self.0.relative_eq(&other.0, epsilon, max_relative)
+                && self.1.relative_eq(&other.1, epsilon, max_relative)
+        }
+    }
+
+    impl approx::UlpsEq for Vec2 {
+        fn default_max_ulps() -> u32 {
+            4
+        }
+
+        fn ulps_eq(&self, other: &Self, epsilon: f64, max_ulps: u32) -> bool {
+            self.0.ulps_eq(&other.0, epsilon, max_ulps)
+                && self.1.ulps_eq(&other.1, epsilon, max_ulps)
+        }
+    }
+
+    #[test]
+    fn tolerance_wrappers_in_approx_macros() {
+        assert_abs_diff_eq!(Decimals::<_, 3>(1.000_1_f64), Decimals(1.000_2));
+        assert!(!abs_diff_eq!(Decimals::<_, 3>(1.0_f64), Decimals(1.01)));
+        assert_abs_diff_eq!(
+            Decimals::<_, 3>(1.0_f64),
+            Decimals(1.01),
+            epsilon = Tolerance::Decimals(1)
+        );
+
+        assert_relative_eq!(SigFigs::<_, 4>(1234.4_f32), SigFigs(1234.0));
+        assert!(relative_eq!(
+            SigFigs::<_, 4>(1e-12_f64),
+            SigFigs(1.000_000_1e-12),
+            max_relative = Tolerance::Rel(1e-6)
+        ));
+
+        assert_ulps_eq!(
+            Decimals::<_, 20>(0.1_f64 + 0.2),
+            Decimals(0.3),
+            max_ulps = 1
+        );
+        assert_abs_diff_eq!(
+            Decimals::<_, 3>(vec![1.0_f64, 2.0]),
+            Decimals(vec![1.000_1, 2.000_1])
+        );
+    }
+
+    #[test]
+    fn approx_types_get_aeq() {
+        let a = Approx(Vec2(1.0, 2.0));
+        let b = Approx(Vec2(1.000_1, 2.000_1));
+
+        assert!(a.aeq(b, 3));
+        assert!(a.nae(b, 5));
+        assert!(Approx(Vec2(1000.0, 1.0)).aeq_sf(Approx(Vec2(1000.4, 1.000_4)), 4));
+        assert!(!Approx(Vec2(1000.0, 1.0)).aeq_sf(Approx(Vec2(1000.4, 1.002)), 4));
+
+        assert!(Approx(Vec2(1000.0, 1.0)).aeq_sf_mode(
+            Approx(Vec2(1000.4, 1.000_4)),
+            4,
+            SfMode::Rounded
+        ));
+
+        let c = Approx(Vec2(0.1 + 0.2, 1.0));
+        assert!(c.aeq_ulps(Approx(Vec2(0.3, 1.0)), 1));
+        assert!(!c.aeq_ulps(Approx(Vec2(0.3, 1.0)), 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "`Approx` only supports `SfMode::Rounded`")]
+    fn approx_types_reject_distance_mode() {
+        let a = Approx(Vec2(1.0, 2.0));
+        let b = Approx(Vec2(1.0, 2.0));
+
+        a.aeq_sf_mode(b, 3, SfMode::Distance(Reference::Max));
+    }
+
+    #[test]
+    fn approx_types_with_f32_epsilon() {
+        let a = Approx(1.0_f32);
+        assert!(a.aeq(Approx(1.000_4), 3));
+        assert!(!a.aeq(Approx(1.002), 3));
+        assert!(a.aeq_sf(Approx(1.000_4), 4));
+        assert!(!a.aeq_sf(Approx(1.002), 4));
+    }
+}
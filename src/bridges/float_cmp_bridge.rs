@@ -0,0 +1,120 @@
+use float_cmp::FloatMargin;
+use num_traits::{Float, NumCast};
+
+use super::{Decimals, SigFigs};
+use crate::special_values::SpecialValuePolicy;
+use crate::{ApproxEq, ApproxEqUlps, ApproxEqWith};
+
+/// `float_cmp::ApproxEq` for a wrapper carrying a tolerance: equal within the tolerance or
+/// within `margin`. Pass `FloatMargin::zero()` to compare within the tolerance only.
+macro_rules! tolerance_wrapper_impl {
+    ($wrapper:ident, $precision:ident) => {
+        impl<T, const $precision: u8> float_cmp::ApproxEq for $wrapper<T, $precision>
+        where
+            T: float_cmp::ApproxEq + ApproxEqWith,
+        {
+            type Margin = T::Margin;
+
+            fn approx_eq<M: Into<T::Margin>>(self, other: Self, margin: M) -> bool {
+                ApproxEqWith::approx_eq(&self.0, &other.0, &Self::tolerance())
+                    || float_cmp::ApproxEq::approx_eq(self.0, other.0, margin)
+            }
+        }
+    };
+}
+
+tolerance_wrapper_impl!(Decimals, D);
+tolerance_wrapper_impl!(SigFigs, S);
+
+/// Adapter giving any `float_cmp::ApproxEq` type this crate's methods.
+///
+/// Special values follow the `float-cmp` impl of the wrapped type, so the policy is ignored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatCmp<T>(pub T);
+
+impl<T> ApproxEq for FloatCmp<T>
+where
+    T: float_cmp::ApproxEq + Copy,
+    <T::Margin as FloatMargin>::F: Float,
+{
+    /// Whether `approx_eq` holds with an epsilon of `10^-decimals` and no ulps.
+    fn aeq_with_policy(&self, other: Self, decimals: u8, _policy: &SpecialValuePolicy) -> bool {
+        let decimals = <<T::Margin as FloatMargin>::F as NumCast>::from(decimals).unwrap();
+        let ten = <<T::Margin as FloatMargin>::F as NumCast>::from(10.0).unwrap();
+        let margin = T::Margin::zero().epsilon(ten.powf(-decimals));
+
+        let aeq = self.0.approx_eq(other.0, margin);
+
+        aeq
+    }
+}
+
+impl<T> ApproxEqUlps for FloatCmp<T>
+where
+    T: float_cmp::ApproxEq + Copy,
+    <T::Margin as FloatMargin>::I: NumCast + num_traits::Bounded,
+{
+    /// Whether `approx_eq` holds with `max_ulps` and a zero epsilon.
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        _policy: &SpecialValuePolicy,
+    ) -> bool {
+        let max_ulps = <<T::Margin as FloatMargin>::I as NumCast>::from(max_ulps)
+            .unwrap_or_else(num_traits::Bounded::max_value);
+        let margin = T::Margin::zero().ulps(max_ulps);
+
+        let aeq_ulps = self.0.approx_eq(other.0, margin);
+
+        aeq_ulps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_cmp::{approx_eq, F32Margin, F64Margin, FloatMargin};
+
+    use super::FloatCmp;
+    use crate::bridges::{Decimals, SigFigs};
+    use crate::{ApproxEq, ApproxEqUlps, NApproxEq};
+
+    #[test]
+    fn tolerance_wrappers_in_float_cmp() {
+        assert!(approx_eq!(
+            Decimals<f64, 3>,
+            Decimals(1.000_1),
+            Decimals(1.000_2),
+            F64Margin::zero()
+        ));
+        assert!(!approx_eq!(
+            Decimals<f64, 3>,
+            Decimals(1.0),
+            Decimals(1.01),
+            F64Margin::zero()
+        ));
+        assert!(approx_eq!(
+            SigFigs<f32, 4>,
+            SigFigs(1234.4),
+            SigFigs(1234.0),
+            F32Margin::zero()
+        ));
+        assert!(approx_eq!(
+            Decimals<f64, 20>,
+            Decimals(0.1 + 0.2),
+            Decimals(0.3),
+            ulps = 1
+        ));
+    }
+
+    #[test]
+    fn float_cmp_types_get_aeq() {
+        assert!(FloatCmp(1.0_f64).aeq(FloatCmp(1.000_1), 3));
+        assert!(FloatCmp(1.0_f64).nae(FloatCmp(1.000_1), 5));
+        assert!(FloatCmp(1.0_f32).aeq(FloatCmp(1.000_1), 3));
+
+        assert!(FloatCmp(0.1_f64 + 0.2).aeq_ulps(FloatCmp(0.3), 1));
+        assert!(!FloatCmp(0.1_f64 + 0.2).aeq_ulps(FloatCmp(0.3), 0));
+        assert!(FloatCmp(1.0_f64).nae_ulps(FloatCmp(2.0), u32::MAX / 2));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod diff;
+pub mod elementwise;
+pub mod grouping;
+pub mod search;
@@ -0,0 +1,373 @@
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Display};
+#[cfg(not(feature = "no_std"))]
+use std::collections::HashMap;
+#[cfg(not(feature = "no_std"))]
+use std::hash::{BuildHasher, Hash};
+
+use num_traits::{Float, NumCast, Zero};
+
+use crate::float_like::FloatLike;
+use crate::special_values::SpecialValuePolicy;
+use crate::tolerance::Tolerance;
+use crate::ApproxEqWith;
+
+/// Number of mismatches listed by the `Display` impl of `DiffReport`.
+const DISPLAYED_MISMATCHES: usize = 10;
+
+/// Collection whose values can be looked up by index or key for `diff`.
+pub trait DiffEntries {
+    /// Index or key of a value.
+    type Key: Clone;
+    /// Compared value.
+    type Value: FloatLike;
+
+    /// Every key with its value, in iteration order.
+    fn entries(&self) -> Vec<(Self::Key, Self::Value)>;
+
+    /// Value under `key`, if present.
+    fn lookup(&self, key: &Self::Key) -> Option<Self::Value>;
+}
+
+impl<F: FloatLike> DiffEntries for [F] {
+    type Key = usize;
+    type Value = F;
+
+    fn entries(&self) -> Vec<(usize, F)> {
+        self.iter().copied().enumerate().collect()
+    }
+
+    fn lookup(&self, key: &usize) -> Option<F> {
+        self.get(*key).copied()
+    }
+}
+
+impl<F: FloatLike, const N: usize> DiffEntries for [F; N] {
+    type Key = usize;
+    type Value = F;
+
+    fn entries(&self) -> Vec<(usize, F)> {
+        self.as_slice().entries()
+    }
+
+    fn lookup(&self, key: &usize) -> Option<F> {
+        self.as_slice().lookup(key)
+    }
+}
+
+impl<F: FloatLike> DiffEntries for Vec<F> {
+    type Key = usize;
+    type Value = F;
+
+    fn entries(&self) -> Vec<(usize, F)> {
+        self.as_slice().entries()
+    }
+
+    fn lookup(&self, key: &usize) -> Option<F> {
+        self.as_slice().lookup(key)
+    }
+}
+
+impl<F: FloatLike> DiffEntries for VecDeque<F> {
+    type Key = usize;
+    type Value = F;
+
+    fn entries(&self) -> Vec<(usize, F)> {
+        self.iter().copied().enumerate().collect()
+    }
+
+    fn lookup(&self, key: &usize) -> Option<F> {
+        self.get(*key).copied()
+    }
+}
+
+impl<K: Ord + Clone, F: FloatLike> DiffEntries for BTreeMap<K, F> {
+    type Key = K;
+    type Value = F;
+
+    fn entries(&self) -> Vec<(K, F)> {
+        self.iter()
+            .map(|(key, value)| (key.clone(), *value))
+            .collect()
+    }
+
+    fn lookup(&self, key: &K) -> Option<F> {
+        self.get(key).copied()
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<K: Eq + Hash + Clone, F: FloatLike, S: BuildHasher> DiffEntries for HashMap<K, F, S> {
+    type Key = K;
+    type Value = F;
+
+    fn entries(&self) -> Vec<(K, F)> {
+        self.iter()
+            .map(|(key, value)| (key.clone(), *value))
+            .collect()
+    }
+
+    fn lookup(&self, key: &K) -> Option<F> {
+        self.get(key).copied()
+    }
+}
+
+/// Entry present in both collections whose values are not approximately equal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch<K, F> {
+    /// Index or key of the entry.
+    pub key: K,
+    /// Value in the first collection.
+    pub first: F,
+    /// Value in the second collection.
+    pub second: F,
+    /// `|first - second|`.
+    pub abs_diff: F,
+}
+
+/// Result of `diff`: where two collections disagree and by how much.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffReport<K, F> {
+    /// Mismatching entries, in the iteration order of the first collection.
+    pub mismatches: Vec<Mismatch<K, F>>,
+    /// Keys present only in the first collection.
+    pub only_in_first: Vec<K>,
+    /// Keys present only in the second collection.
+    pub only_in_second: Vec<K>,
+    /// Number of keys present in both collections.
+    pub compared: usize,
+    /// Largest absolute difference over all compared entries, ignoring NaN differences.
+    pub max_abs_diff: F,
+    /// Mean absolute difference over all compared entries, ignoring NaN differences.
+    pub mean_abs_diff: F,
+}
+
+impl<K, F: FloatLike> DiffReport<K, F> {
+    /// Whether the collections have the same keys and every value matched.
+    pub fn is_empty(&self) -> bool {
+        self.mismatches.is_empty()
+            && self.only_in_first.is_empty()
+            && self.only_in_second.is_empty()
+    }
+
+    /// Mismatch with the largest absolute difference; a NaN difference counts as the largest.
+    pub fn worst(&self) -> Option<&Mismatch<K, F>> {
+        self.mismatches.iter().reduce(|worst, mismatch| {
+            if worst.abs_diff.is_nan() || mismatch.abs_diff <= worst.abs_diff {
+                worst
+            } else {
+                mismatch
+            }
+        })
+    }
+}
+
+impl<K: Debug, F: FloatLike> Display for DiffReport<K, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} compared entries mismatch",
+            self.mismatches.len(),
+            self.compared
+        )?;
+        write!(
+            f,
+            "\nmax abs diff: {:?}, mean abs diff: {:?}",
+            self.max_abs_diff, self.mean_abs_diff
+        )?;
+
+        if let Some(worst) = self.worst() {
+            write!(
+                f,
+                "\n       worst: [{:?}] {:?} vs {:?}",
+                worst.key, worst.first, worst.second
+            )?;
+        }
+
+        for mismatch in self.mismatches.iter().take(DISPLAYED_MISMATCHES) {
+            write!(
+                f,
+                "\n  [{:?}] {:?} vs {:?} (abs diff {:?})",
+                mismatch.key, mismatch.first, mismatch.second, mismatch.abs_diff
+            )?;
+        }
+        if self.mismatches.len() > DISPLAYED_MISMATCHES {
+            write!(
+                f,
+                "\n  ... and {} more",
+                self.mismatches.len() - DISPLAYED_MISMATCHES
+            )?;
+        }
+
+        if !self.only_in_first.is_empty() {
+            write!(f, "\nonly in first: {:?}", self.only_in_first)?;
+        }
+        if !self.only_in_second.is_empty() {
+            write!(f, "\nonly in second: {:?}", self.only_in_second)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Entries of `first` and `second` that are not equal within `tolerance`.
+pub fn diff<C>(first: &C, second: &C, tolerance: &Tolerance) -> DiffReport<C::Key, C::Value>
+where
+    C: DiffEntries + ?Sized,
+{
+    diff_with_policy(first, second, tolerance, &SpecialValuePolicy::default())
+}
+
+/// Entries of `first` and `second` that are not equal within `tolerance`, treating special
+/// values as `policy` specifies.
+pub fn diff_with_policy<C>(
+    first: &C,
+    second: &C,
+    tolerance: &Tolerance,
+    policy: &SpecialValuePolicy,
+) -> DiffReport<C::Key, C::Value>
+where
+    C: DiffEntries + ?Sized,
+{
+    let mut mismatches = Vec::new();
+    let mut only_in_first = Vec::new();
+    let mut compared = 0;
+    let mut max_abs_diff = C::Value::zero();
+    let mut sum_abs_diff = C::Value::zero();
+    let mut summed = 0_usize;
+
+    for (key, first_value) in first.entries() {
+        let Some(second_value) = second.lookup(&key) else {
+            only_in_first.push(key);
+            continue;
+        };
+
+        compared += 1;
+        let abs_diff = (first_value - second_value).abs();
+        if !abs_diff.is_nan() {
+            max_abs_diff = max_abs_diff.max(abs_diff);
+            sum_abs_diff = sum_abs_diff + abs_diff;
+            summed += 1;
+        }
+
+        if first_value.approx_ne_with_policy(&second_value, tolerance, policy) {
+            mismatches.push(Mismatch {
+                key,
+                first: first_value,
+                second: second_value,
+                abs_diff,
+            });
+        }
+    }
+
+    let only_in_second = second
+        .entries()
+        .into_iter()
+        .filter(|(key, _)| first.lookup(key).is_none())
+        .map(|(key, _)| key)
+        .collect();
+
+    let mean_abs_diff = if summed == 0 {
+        C::Value::zero()
+    } else {
+        sum_abs_diff / <C::Value as NumCast>::from(summed).unwrap_or_else(C::Value::nan)
+    };
+
+    DiffReport {
+        mismatches,
+        only_in_first,
+        only_in_second,
+        compared,
+        max_abs_diff,
+        mean_abs_diff,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use super::{diff, diff_with_policy};
+    use crate::special_values::SpecialValuePolicy;
+    use crate::tolerance::Tolerance;
+
+    #[test]
+    fn diff_vec_points_to_bad_entries() {
+        let expected = (0..10_000).map(f64::from).collect::<Vec<_>>();
+        let mut actual = expected.clone();
+        actual[17] += 1e-3;
+        actual[4_242] -= 0.5;
+        actual[9_999] += 1e-12;
+
+        let report = diff(&actual, &expected, &Tolerance::Abs(1e-9));
+        assert!(!report.is_empty());
+        assert_eq!(report.compared, 10_000);
+        assert_eq!(
+            report
+                .mismatches
+                .iter()
+                .map(|mismatch| mismatch.key)
+                .collect::<Vec<_>>(),
+            vec![17, 4_242]
+        );
+        assert_eq!(report.worst().unwrap().key, 4_242);
+        assert!((report.max_abs_diff - 0.5).abs() < 1e-12);
+
+        let report = diff(&actual, &expected, &Tolerance::Abs(1.0));
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn diff_lengths_and_keys() {
+        let report = diff(
+            &[1.0_f32, 2.0, 3.0][..],
+            &[1.0, 2.0][..],
+            &Tolerance::Ulps(0),
+        );
+        assert_eq!(report.compared, 2);
+        assert_eq!(report.only_in_first, vec![2]);
+        assert!(report.only_in_second.is_empty());
+
+        let first = BTreeMap::from([("a", 1.0_f64), ("b", 2.0)]);
+        let second = BTreeMap::from([("b", 2.1_f64), ("c", 3.0)]);
+        let report = diff(&first, &second, &Tolerance::Decimals(3));
+        assert_eq!(report.only_in_first, vec!["a"]);
+        assert_eq!(report.only_in_second, vec!["c"]);
+        assert_eq!(report.mismatches[0].key, "b");
+
+        let first = HashMap::from([(1, 1.0_f64)]);
+        let second = HashMap::from([(1, 1.0_f64)]);
+        assert!(diff(&first, &second, &Tolerance::Ulps(0)).is_empty());
+    }
+
+    #[test]
+    fn diff_special_values() {
+        let first = vec![f64::NAN, 1.0];
+        let second = vec![f64::NAN, 2.0];
+
+        let report = diff(&first, &second, &Tolerance::Abs(1e-9));
+        assert_eq!(report.worst().unwrap().key, 0);
+        assert!((report.max_abs_diff - 1.0).abs() < 1e-12);
+        assert!((report.mean_abs_diff - 1.0).abs() < 1e-12);
+
+        let policy = SpecialValuePolicy {
+            nan_equal: true,
+            ..SpecialValuePolicy::default()
+        };
+        let report = diff_with_policy(&first, &second, &Tolerance::Abs(1e-9), &policy);
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.worst().unwrap().key, 1);
+    }
+
+    #[test]
+    fn display_lists_mismatches() {
+        let first = vec![1.0_f64; 12];
+        let second = vec![2.0_f64; 12];
+
+        let report = diff(&first, &second, &Tolerance::Abs(0.5)).to_string();
+        assert!(report.starts_with("12 of 12 compared entries mismatch"));
+        assert!(report.contains("[0] 1.0 vs 2.0"));
+        assert!(report.contains("... and 2 more"));
+    }
+}
@@ -0,0 +1,745 @@
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::vec::Vec;
+#[cfg(not(feature = "no_std"))]
+use std::collections::HashMap;
+#[cfg(not(feature = "no_std"))]
+use std::hash::{BuildHasher, Hash};
+
+use crate::decimals::round_dec::RoundingMode;
+use crate::relative::approx_eq_rel::Reference;
+use crate::relative::is_close::IsCloseTolerance;
+use crate::significant_figures::approx_eq_sf::SfMode;
+use crate::special_values::SpecialValuePolicy;
+use crate::tolerance::Tolerance;
+use crate::{
+    ApproxEq, ApproxEqRel, ApproxEqRounded, ApproxEqSf, ApproxEqUlps, ApproxEqWith, IsClose,
+};
+
+/// Whether both sequences have the same length and `eq` holds for every pair of elements.
+fn elementwise<'a, T: 'a, U>(
+    first: impl ExactSizeIterator<Item = &'a T>,
+    second: impl ExactSizeIterator<Item = U>,
+    mut eq: impl FnMut(&T, U) -> bool,
+) -> bool {
+    first.len() == second.len() && first.zip(second).all(|(first, second)| eq(first, second))
+}
+
+/// Whether both maps have the same key set and `eq` holds for the values under every key.
+fn keywise<'a, K, T: 'a, U>(
+    first_len: usize,
+    get: impl Fn(&K) -> Option<&'a T>,
+    second: impl ExactSizeIterator<Item = (K, U)>,
+    mut eq: impl FnMut(&T, U) -> bool,
+) -> bool {
+    first_len == second.len()
+        && second
+            .into_iter()
+            .all(|(key, second)| get(&key).is_some_and(|first| eq(first, second)))
+}
+
+impl<T: ApproxEq + Clone> ApproxEq for &[T] {
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
+        let aeq = elementwise(self.iter(), other.iter().cloned(), |first, second| {
+            first.aeq_with_policy(second, decimals, policy)
+        });
+
+        aeq
+    }
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
+        let aeq = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_with_policy(second, decimals, policy)
+        });
+
+        aeq
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
+        let aeq = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_with_policy(second, decimals, policy)
+        });
+
+        aeq
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for VecDeque<T> {
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
+        let aeq = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_with_policy(second, decimals, policy)
+        });
+
+        aeq
+    }
+}
+
+impl<K: Ord, T: ApproxEq> ApproxEq for BTreeMap<K, T> {
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
+        let aeq = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.into_iter(),
+            |first, second| first.aeq_with_policy(second, decimals, policy),
+        );
+
+        aeq
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<K: Eq + Hash, T: ApproxEq, S: BuildHasher> ApproxEq for HashMap<K, T, S> {
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
+        let aeq = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.into_iter(),
+            |first, second| first.aeq_with_policy(second, decimals, policy),
+        );
+
+        aeq
+    }
+}
+
+impl<T: ApproxEqSf + Clone> ApproxEqSf for &[T] {
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_sf = elementwise(self.iter(), other.iter().cloned(), |first, second| {
+            first.aeq_sf_mode_with_policy(second, significant_figures, mode, policy)
+        });
+
+        aeq_sf
+    }
+}
+
+impl<T: ApproxEqSf, const N: usize> ApproxEqSf for [T; N] {
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_sf = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_sf_mode_with_policy(second, significant_figures, mode, policy)
+        });
+
+        aeq_sf
+    }
+}
+
+impl<T: ApproxEqSf> ApproxEqSf for Vec<T> {
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_sf = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_sf_mode_with_policy(second, significant_figures, mode, policy)
+        });
+
+        aeq_sf
+    }
+}
+
+impl<T: ApproxEqSf> ApproxEqSf for VecDeque<T> {
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_sf = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_sf_mode_with_policy(second, significant_figures, mode, policy)
+        });
+
+        aeq_sf
+    }
+}
+
+impl<K: Ord, T: ApproxEqSf> ApproxEqSf for BTreeMap<K, T> {
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_sf = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.into_iter(),
+            |first, second| {
+                first.aeq_sf_mode_with_policy(second, significant_figures, mode, policy)
+            },
+        );
+
+        aeq_sf
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<K: Eq + Hash, T: ApproxEqSf, S: BuildHasher> ApproxEqSf for HashMap<K, T, S> {
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_sf = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.into_iter(),
+            |first, second| {
+                first.aeq_sf_mode_with_policy(second, significant_figures, mode, policy)
+            },
+        );
+
+        aeq_sf
+    }
+}
+
+impl<T: ApproxEqRounded + Clone> ApproxEqRounded for &[T] {
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_rounded = elementwise(self.iter(), other.iter().cloned(), |first, second| {
+            first.aeq_rounded_mode_with_policy(second, decimals, mode, policy)
+        });
+
+        aeq_rounded
+    }
+}
+
+impl<T: ApproxEqRounded, const N: usize> ApproxEqRounded for [T; N] {
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_rounded = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_rounded_mode_with_policy(second, decimals, mode, policy)
+        });
+
+        aeq_rounded
+    }
+}
+
+impl<T: ApproxEqRounded> ApproxEqRounded for Vec<T> {
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_rounded = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_rounded_mode_with_policy(second, decimals, mode, policy)
+        });
+
+        aeq_rounded
+    }
+}
+
+impl<T: ApproxEqRounded> ApproxEqRounded for VecDeque<T> {
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_rounded = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_rounded_mode_with_policy(second, decimals, mode, policy)
+        });
+
+        aeq_rounded
+    }
+}
+
+impl<K: Ord, T: ApproxEqRounded> ApproxEqRounded for BTreeMap<K, T> {
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_rounded = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.into_iter(),
+            |first, second| first.aeq_rounded_mode_with_policy(second, decimals, mode, policy),
+        );
+
+        aeq_rounded
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<K: Eq + Hash, T: ApproxEqRounded, S: BuildHasher> ApproxEqRounded for HashMap<K, T, S> {
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_rounded = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.into_iter(),
+            |first, second| first.aeq_rounded_mode_with_policy(second, decimals, mode, policy),
+        );
+
+        aeq_rounded
+    }
+}
+
+impl<T: ApproxEqRel + Clone> ApproxEqRel for &[T] {
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_rel = elementwise(self.iter(), other.iter().cloned(), |first, second| {
+            first.aeq_rel_to_with_policy(second, rel_tol, reference, policy)
+        });
+
+        aeq_rel
+    }
+}
+
+impl<T: ApproxEqRel, const N: usize> ApproxEqRel for [T; N] {
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_rel = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_rel_to_with_policy(second, rel_tol, reference, policy)
+        });
+
+        aeq_rel
+    }
+}
+
+impl<T: ApproxEqRel> ApproxEqRel for Vec<T> {
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_rel = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_rel_to_with_policy(second, rel_tol, reference, policy)
+        });
+
+        aeq_rel
+    }
+}
+
+impl<T: ApproxEqRel> ApproxEqRel for VecDeque<T> {
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_rel = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_rel_to_with_policy(second, rel_tol, reference, policy)
+        });
+
+        aeq_rel
+    }
+}
+
+impl<K: Ord, T: ApproxEqRel> ApproxEqRel for BTreeMap<K, T> {
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_rel = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.into_iter(),
+            |first, second| first.aeq_rel_to_with_policy(second, rel_tol, reference, policy),
+        );
+
+        aeq_rel
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<K: Eq + Hash, T: ApproxEqRel, S: BuildHasher> ApproxEqRel for HashMap<K, T, S> {
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_rel = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.into_iter(),
+            |first, second| first.aeq_rel_to_with_policy(second, rel_tol, reference, policy),
+        );
+
+        aeq_rel
+    }
+}
+
+impl<T: ApproxEqUlps + Clone> ApproxEqUlps for &[T] {
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_ulps = elementwise(self.iter(), other.iter().cloned(), |first, second| {
+            first.aeq_ulps_with_policy(second, max_ulps, policy)
+        });
+
+        aeq_ulps
+    }
+}
+
+impl<T: ApproxEqUlps, const N: usize> ApproxEqUlps for [T; N] {
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_ulps = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_ulps_with_policy(second, max_ulps, policy)
+        });
+
+        aeq_ulps
+    }
+}
+
+impl<T: ApproxEqUlps> ApproxEqUlps for Vec<T> {
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_ulps = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_ulps_with_policy(second, max_ulps, policy)
+        });
+
+        aeq_ulps
+    }
+}
+
+impl<T: ApproxEqUlps> ApproxEqUlps for VecDeque<T> {
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_ulps = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.aeq_ulps_with_policy(second, max_ulps, policy)
+        });
+
+        aeq_ulps
+    }
+}
+
+impl<K: Ord, T: ApproxEqUlps> ApproxEqUlps for BTreeMap<K, T> {
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_ulps = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.into_iter(),
+            |first, second| first.aeq_ulps_with_policy(second, max_ulps, policy),
+        );
+
+        aeq_ulps
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<K: Eq + Hash, T: ApproxEqUlps, S: BuildHasher> ApproxEqUlps for HashMap<K, T, S> {
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let aeq_ulps = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.into_iter(),
+            |first, second| first.aeq_ulps_with_policy(second, max_ulps, policy),
+        );
+
+        aeq_ulps
+    }
+}
+
+impl<T: IsClose + Clone> IsClose for &[T] {
+    fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+        let is_close = elementwise(self.iter(), other.iter().cloned(), |first, second| {
+            first.is_close(second, tolerance)
+        });
+
+        is_close
+    }
+}
+
+impl<T: IsClose, const N: usize> IsClose for [T; N] {
+    fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+        let is_close = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.is_close(second, tolerance)
+        });
+
+        is_close
+    }
+}
+
+impl<T: IsClose> IsClose for Vec<T> {
+    fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+        let is_close = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.is_close(second, tolerance)
+        });
+
+        is_close
+    }
+}
+
+impl<T: IsClose> IsClose for VecDeque<T> {
+    fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+        let is_close = elementwise(self.iter(), other.into_iter(), |first, second| {
+            first.is_close(second, tolerance)
+        });
+
+        is_close
+    }
+}
+
+impl<K: Ord, T: IsClose> IsClose for BTreeMap<K, T> {
+    fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+        let is_close = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.into_iter(),
+            |first, second| first.is_close(second, tolerance),
+        );
+
+        is_close
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<K: Eq + Hash, T: IsClose, S: BuildHasher> IsClose for HashMap<K, T, S> {
+    fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+        let is_close = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.into_iter(),
+            |first, second| first.is_close(second, tolerance),
+        );
+
+        is_close
+    }
+}
+
+impl<T: ApproxEqWith> ApproxEqWith for [T] {
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let approx_eq = elementwise(self.iter(), other.iter(), |first, second| {
+            first.approx_eq_with_policy(second, tolerance, policy)
+        });
+
+        approx_eq
+    }
+}
+
+impl<T: ApproxEqWith, const N: usize> ApproxEqWith for [T; N] {
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let approx_eq = elementwise(self.iter(), other.iter(), |first, second| {
+            first.approx_eq_with_policy(second, tolerance, policy)
+        });
+
+        approx_eq
+    }
+}
+
+impl<T: ApproxEqWith> ApproxEqWith for Vec<T> {
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let approx_eq = elementwise(self.iter(), other.iter(), |first, second| {
+            first.approx_eq_with_policy(second, tolerance, policy)
+        });
+
+        approx_eq
+    }
+}
+
+impl<T: ApproxEqWith> ApproxEqWith for VecDeque<T> {
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let approx_eq = elementwise(self.iter(), other.iter(), |first, second| {
+            first.approx_eq_with_policy(second, tolerance, policy)
+        });
+
+        approx_eq
+    }
+}
+
+impl<K: Ord, T: ApproxEqWith> ApproxEqWith for BTreeMap<K, T> {
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let approx_eq = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.iter(),
+            |first, second| first.approx_eq_with_policy(second, tolerance, policy),
+        );
+
+        approx_eq
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<K: Eq + Hash, T: ApproxEqWith, S: BuildHasher> ApproxEqWith for HashMap<K, T, S> {
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let approx_eq = keywise(
+            self.len(),
+            |key| self.get(key),
+            other.iter(),
+            |first, second| first.approx_eq_with_policy(second, tolerance, policy),
+        );
+
+        approx_eq
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap, VecDeque};
+
+    use crate::relative::is_close::IsCloseTolerance;
+    use crate::tolerance::Tolerance;
+    use crate::{
+        ApproxEq, ApproxEqRel, ApproxEqRounded, ApproxEqSf, ApproxEqUlps, ApproxEqWith, IsClose,
+        NApproxEq,
+    };
+
+    #[test]
+    fn aeq_sequences() {
+        let a = vec![1.000_1_f64, 2.000_1, 3.000_1];
+        let b = vec![1.000_2_f64, 2.000_2, 3.000_2];
+        assert!(a.aeq(b.clone(), 3));
+        assert!(a.nae(b.clone(), 5));
+        assert!(a.as_slice().aeq(b.as_slice(), 3));
+        assert!(a.aeq_sf(b.clone(), 4));
+        assert!(a.aeq_rel(b.clone(), 1e-4));
+        let tolerance = IsCloseTolerance {
+            rel_tol: 1e-4,
+            ..IsCloseTolerance::python()
+        };
+        assert!(a.is_close(b.clone(), &tolerance));
+        assert!(a.approx_eq(&b, &Tolerance::Abs(1e-3)));
+        assert!(a[..].approx_eq(&b[..], &Tolerance::Abs(1e-3)));
+
+        assert!([0.1_f32 + 0.2].aeq_ulps([0.3], 1));
+        assert!([1.234_4_f64, 2.0].aeq_rounded([1.234_3, 2.0], 3));
+
+        let a = VecDeque::from([Some(1.0_f64), None]);
+        let b = VecDeque::from([Some(1.0_f64), None]);
+        assert!(a.aeq(b, 9));
+    }
+
+    #[test]
+    fn length_mismatch() {
+        let a = vec![1.0_f64, 2.0];
+        let b = vec![1.0_f64];
+        assert!(a.nae(b.clone(), 3));
+        assert!(b.nae(a.clone(), 3));
+        assert!(a.approx_ne(&vec![1.0, 2.0, 3.0], &Tolerance::Abs(1.0)));
+    }
+
+    #[test]
+    fn aeq_maps() {
+        let a = BTreeMap::from([("x", 1.000_1_f64), ("y", 2.0)]);
+        let b = BTreeMap::from([("x", 1.000_2_f64), ("y", 2.0)]);
+        assert!(a.aeq(b.clone(), 3));
+        assert!(a.approx_eq(&b, &Tolerance::Decimals(3)));
+
+        let c = BTreeMap::from([("x", 1.000_2_f64), ("z", 2.0)]);
+        assert!(a.nae(c.clone(), 3));
+        assert!(a.approx_ne(&c, &Tolerance::Decimals(3)));
+
+        let a = HashMap::from([(1, 1.000_1_f32), (2, 2.0)]);
+        let b = HashMap::from([(2, 2.0_f32), (1, 1.000_2)]);
+        assert!(a.aeq(b.clone(), 3));
+        assert!(a.nae(b, 5));
+
+        let b = HashMap::from([(1, 1.000_1_f32)]);
+        assert!(a.nae(b, 3));
+    }
+}
@@ -0,0 +1,241 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::float_like::FloatLike;
+use crate::special_values::SpecialValuePolicy;
+use crate::tolerance::Tolerance;
+use crate::ApproxEqWith;
+
+/// Which value stands for a group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Representative {
+    /// The first member, in input order.
+    #[default]
+    First,
+    /// The mean of the members.
+    Mean,
+    /// The median of the members, the mean of the middle two for an even count.
+    Median,
+}
+
+/// Values merged into one group, with the value standing for them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group<F> {
+    /// Value chosen by the `Representative` policy.
+    pub representative: F,
+    /// Members of the group, in input order for `approx_dedup` and ascending otherwise.
+    pub members: Vec<F>,
+}
+
+impl<F: FloatLike> Group<F> {
+    fn new(members: Vec<F>, representative: Representative) -> Self {
+        let representative = match representative {
+            Representative::First => members[0],
+            Representative::Mean => {
+                let sum = members.iter().fold(F::zero(), |sum, member| sum + *member);
+                sum / F::from(members.len()).unwrap_or_else(F::nan)
+            }
+            Representative::Median => {
+                let mut sorted = members.clone();
+                sorted.sort_by(total_cmp);
+                let middle = sorted.len() / 2;
+
+                if sorted.len() % 2 == 0 {
+                    (sorted[middle - 1] + sorted[middle]) / (F::one() + F::one())
+                } else {
+                    sorted[middle]
+                }
+            }
+        };
+
+        Self {
+            representative,
+            members,
+        }
+    }
+}
+
+/// Ascending order with NaN after every number.
+fn total_cmp<F: FloatLike>(first: &F, second: &F) -> Ordering {
+    first
+        .partial_cmp(second)
+        .unwrap_or_else(|| first.is_nan().cmp(&second.is_nan()))
+}
+
+/// Splits `values` into runs of consecutive values, extending the current run while
+/// `same_run(run, value)` holds.
+fn runs<F: FloatLike>(
+    values: impl IntoIterator<Item = F>,
+    representative: Representative,
+    mut same_run: impl FnMut(&[F], &F) -> bool,
+) -> Vec<Group<F>> {
+    let mut runs: Vec<Vec<F>> = Vec::new();
+
+    for value in values {
+        match runs.last_mut() {
+            Some(run) if same_run(run, &value) => run.push(value),
+            _ => runs.push(vec![value]),
+        }
+    }
+
+    runs.into_iter()
+        .map(|members| Group::new(members, representative))
+        .collect()
+}
+
+/// Groups consecutive values equal within `tolerance` to the first value of their group.
+pub fn approx_dedup<F: FloatLike>(
+    values: impl IntoIterator<Item = F>,
+    tolerance: &Tolerance,
+    representative: Representative,
+) -> Vec<Group<F>> {
+    approx_dedup_with_policy(
+        values,
+        tolerance,
+        representative,
+        &SpecialValuePolicy::default(),
+    )
+}
+
+/// `approx_dedup`, treating special values as `policy` specifies.
+pub fn approx_dedup_with_policy<F: FloatLike>(
+    values: impl IntoIterator<Item = F>,
+    tolerance: &Tolerance,
+    representative: Representative,
+    policy: &SpecialValuePolicy,
+) -> Vec<Group<F>> {
+    runs(values, representative, |run, value| {
+        run[0].approx_eq_with_policy(value, tolerance, policy)
+    })
+}
+
+/// Sorts the values, then groups those equal within `tolerance` to the smallest value of their
+/// group.
+pub fn approx_unique<F: FloatLike>(
+    values: impl IntoIterator<Item = F>,
+    tolerance: &Tolerance,
+    representative: Representative,
+) -> Vec<Group<F>> {
+    approx_unique_with_policy(
+        values,
+        tolerance,
+        representative,
+        &SpecialValuePolicy::default(),
+    )
+}
+
+/// `approx_unique`, treating special values as `policy` specifies.
+pub fn approx_unique_with_policy<F: FloatLike>(
+    values: impl IntoIterator<Item = F>,
+    tolerance: &Tolerance,
+    representative: Representative,
+    policy: &SpecialValuePolicy,
+) -> Vec<Group<F>> {
+    let mut sorted = values.into_iter().collect::<Vec<_>>();
+    sorted.sort_by(total_cmp);
+
+    approx_dedup_with_policy(sorted, tolerance, representative, policy)
+}
+
+/// Single-linkage clusters: sorted values where each is equal within `tolerance` to its
+/// neighbour, so a cluster can span more than the tolerance.
+pub fn cluster<F: FloatLike>(
+    values: impl IntoIterator<Item = F>,
+    tolerance: &Tolerance,
+    representative: Representative,
+) -> Vec<Group<F>> {
+    cluster_with_policy(
+        values,
+        tolerance,
+        representative,
+        &SpecialValuePolicy::default(),
+    )
+}
+
+/// `cluster`, treating special values as `policy` specifies.
+pub fn cluster_with_policy<F: FloatLike>(
+    values: impl IntoIterator<Item = F>,
+    tolerance: &Tolerance,
+    representative: Representative,
+    policy: &SpecialValuePolicy,
+) -> Vec<Group<F>> {
+    let mut sorted = values.into_iter().collect::<Vec<_>>();
+    sorted.sort_by(total_cmp);
+
+    runs(sorted, representative, |run, value| {
+        run[run.len() - 1].approx_eq_with_policy(value, tolerance, policy)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{approx_dedup, approx_unique, cluster, cluster_with_policy, Representative};
+    use crate::special_values::SpecialValuePolicy;
+    use crate::tolerance::Tolerance;
+
+    #[test]
+    fn approx_dedup_consecutive() {
+        let values = [1.0_f64, 1.000_2, 1.000_4, 2.0, 1.0];
+        let groups = approx_dedup(values, &Tolerance::Decimals(3), Representative::First);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].members, vec![1.0, 1.000_2, 1.000_4]);
+        assert!((groups[0].representative - 1.0).abs() < 1e-12);
+        assert_eq!(groups[2].members, vec![1.0]);
+
+        let groups = approx_dedup(
+            values.iter().copied().filter(|value| *value < 1.5),
+            &Tolerance::Decimals(3),
+            Representative::Mean,
+        );
+        assert_eq!(groups.len(), 1);
+        assert!((groups[0].representative - 1.000_15).abs() < 1e-12);
+    }
+
+    #[test]
+    fn approx_unique_sorts_first() {
+        let values = vec![2.0_f32, 1.000_1, 3.0, 1.0, 2.000_1, 1.000_3];
+        let groups = approx_unique(values, &Tolerance::SigFigs(3), Representative::Median);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].members, vec![1.0, 1.000_1, 1.000_3]);
+        assert!((groups[0].representative - 1.000_1).abs() < 1e-6);
+        assert!((groups[1].representative - 2.000_05).abs() < 1e-6);
+    }
+
+    #[test]
+    fn cluster_chains_neighbours() {
+        // Each step is below the tolerance, the span is not.
+        let peaks = [10.0_f64, 10.4, 10.8, 11.2, 20.0, 19.7];
+        let tolerance = Tolerance::Abs(0.5);
+
+        let clusters = cluster(peaks, &tolerance, Representative::Mean);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].members, vec![10.0, 10.4, 10.8, 11.2]);
+        assert!((clusters[0].representative - 10.6).abs() < 1e-12);
+        assert_eq!(clusters[1].members, vec![19.7, 20.0]);
+
+        assert_eq!(
+            approx_unique(peaks, &tolerance, Representative::First).len(),
+            3
+        );
+    }
+
+    #[test]
+    fn cluster_special_values() {
+        let values = [f64::NAN, 1.0, f64::NAN];
+        let tolerance = Tolerance::Abs(0.1);
+        assert_eq!(cluster(values, &tolerance, Representative::First).len(), 3);
+
+        let policy = SpecialValuePolicy {
+            nan_equal: true,
+            ..SpecialValuePolicy::default()
+        };
+        let clusters = cluster_with_policy(values, &tolerance, Representative::First, &policy);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[1].members.len(), 2);
+
+        assert!(cluster(Vec::<f64>::new(), &tolerance, Representative::Median).is_empty());
+    }
+}
@@ -0,0 +1,119 @@
+use core::cmp::Ordering;
+use core::ops::Range;
+
+use crate::float_like::FloatLike;
+use crate::special_values::SpecialValuePolicy;
+use crate::tolerance::Tolerance;
+use crate::{ApproxEqWith, ApproxOrd};
+
+/// Searches the ascending `values` for the elements equal to `target` within `tolerance`.
+///
+/// Returns `Ok` with the index range of every such element. NaN is expected after every number.
+///
+/// # Errors
+///
+/// `Err` with the index where `target` could be inserted keeping the order, if no element
+/// matches.
+pub fn approx_binary_search<F: FloatLike>(
+    values: &[F],
+    target: F,
+    tolerance: &Tolerance,
+) -> Result<Range<usize>, usize> {
+    approx_binary_search_with_policy(values, target, tolerance, &SpecialValuePolicy::default())
+}
+
+/// `approx_binary_search`, treating special values as `policy` specifies.
+///
+/// # Errors
+///
+/// `Err` with the insertion index of `target`, if no element matches.
+pub fn approx_binary_search_with_policy<F: FloatLike>(
+    values: &[F],
+    target: F,
+    tolerance: &Tolerance,
+    policy: &SpecialValuePolicy,
+) -> Result<Range<usize>, usize> {
+    let acmp = |value: &F| value.acmp_with_policy(&target, tolerance, policy);
+    let start = values.partition_point(|value| acmp(value) == Ordering::Less);
+    let end = values.partition_point(|value| acmp(value) != Ordering::Greater);
+
+    // `acmp` orders NaN equal to NaN even where `policy` does not.
+    if start == end || !values[start].approx_eq_with_policy(&target, tolerance, policy) {
+        return Err(start);
+    }
+
+    Ok(start..end)
+}
+
+/// Whether the ascending `values` contain an element equal to `target` within `tolerance`.
+pub fn approx_contains<F: FloatLike>(values: &[F], target: F, tolerance: &Tolerance) -> bool {
+    approx_binary_search(values, target, tolerance).is_ok()
+}
+
+/// Index of the first element of the ascending `values` equal to `target` within `tolerance`.
+pub fn approx_position<F: FloatLike>(
+    values: &[F],
+    target: F,
+    tolerance: &Tolerance,
+) -> Option<usize> {
+    approx_binary_search(values, target, tolerance)
+        .ok()
+        .map(|range| range.start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        approx_binary_search, approx_binary_search_with_policy, approx_contains, approx_position,
+    };
+    use crate::special_values::SpecialValuePolicy;
+    use crate::tolerance::Tolerance;
+
+    #[test]
+    fn binary_search_decimals() {
+        let table = [0.5_f64, 0.999_9, 1.0, 1.000_3, 1.002, 2.0];
+        let tolerance = Tolerance::Decimals(3);
+
+        assert_eq!(approx_binary_search(&table, 1.000_1, &tolerance), Ok(1..4));
+        assert_eq!(approx_binary_search(&table, 1.5, &tolerance), Err(5));
+        assert_eq!(approx_binary_search(&table, 3.0, &tolerance), Err(6));
+        assert_eq!(approx_binary_search(&table, 0.0, &tolerance), Err(0));
+        assert_eq!(approx_binary_search(&[], 0.0_f64, &tolerance), Err(0));
+
+        assert!(approx_contains(&table, 2.000_4, &tolerance));
+        assert_eq!(approx_position(&table, 1.002_3, &tolerance), Some(4));
+        assert_eq!(approx_position(&table, 1.01, &tolerance), None);
+    }
+
+    #[test]
+    fn binary_search_sig_figs() {
+        let table = [1200.0_f32, 1234.0, 1234.4, 1235.0, 1300.0];
+
+        assert_eq!(
+            approx_binary_search(&table, 1234.2, &Tolerance::SigFigs(4)),
+            Ok(1..3)
+        );
+        assert_eq!(
+            approx_binary_search(&table, 1234.2, &Tolerance::SigFigs(2)),
+            Ok(0..4)
+        );
+    }
+
+    #[test]
+    fn binary_search_nan() {
+        let table = [1.0_f64, 2.0, f64::NAN];
+        let tolerance = Tolerance::Abs(0.1);
+
+        assert_eq!(approx_binary_search(&table, f64::NAN, &tolerance), Err(2));
+        assert_eq!(approx_binary_search(&table, 2.05, &tolerance), Ok(1..2));
+
+        let policy = SpecialValuePolicy {
+            nan_equal: true,
+            ..SpecialValuePolicy::default()
+        };
+        assert_eq!(
+            approx_binary_search_with_policy(&table, f64::NAN, &tolerance, &policy),
+            Ok(2..3)
+        );
+    }
+}
@@ -0,0 +1,674 @@
+use alloc::borrow::{Cow, ToOwned};
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::decimals::round_dec::RoundingMode;
+use crate::derive::{join_path, ApproxEqDiff, ApproxEqSfDiff};
+use crate::relative::approx_eq_rel::Reference;
+use crate::relative::is_close::IsCloseTolerance;
+use crate::significant_figures::approx_eq_sf::SfMode;
+use crate::special_values::SpecialValuePolicy;
+use crate::tolerance::Tolerance;
+use crate::{
+    ApproxEq, ApproxEqRel, ApproxEqRounded, ApproxEqSf, ApproxEqUlps, ApproxEqWith, IsClose,
+};
+
+/// Implements every comparison trait for tuples, element by element, and for boxed tuples.
+macro_rules! tuple_impls {
+    ($(($($name:ident $index:tt),+))+) => {
+        $(
+            impl<$($name: ApproxEq),+> ApproxEq for ($($name,)+) {
+                fn aeq_with_policy(
+                    &self,
+                    other: Self,
+                    decimals: u8,
+                    policy: &SpecialValuePolicy,
+                ) -> bool {
+                    $(self.$index.aeq_with_policy(other.$index, decimals, policy))&&+
+                }
+            }
+
+            impl<$($name: ApproxEqDiff),+> ApproxEqDiff for ($($name,)+) {
+                fn aeq_diff_with_policy(
+                    &self,
+                    other: Self,
+                    decimals: u8,
+                    policy: &SpecialValuePolicy,
+                ) -> Vec<String> {
+                    let mut mismatches = Vec::new();
+                    $(
+                        let paths =
+                            self.$index.aeq_diff_with_policy(other.$index, decimals, policy);
+                        mismatches.extend(
+                            paths.iter().map(|path| join_path(stringify!($index), path)),
+                        );
+                    )+
+                    mismatches
+                }
+            }
+
+            impl<$($name: ApproxEqSf),+> ApproxEqSf for ($($name,)+) {
+                fn aeq_sf_mode_with_policy(
+                    &self,
+                    other: Self,
+                    significant_figures: u8,
+                    mode: SfMode,
+                    policy: &SpecialValuePolicy,
+                ) -> bool {
+                    $(
+                        self.$index.aeq_sf_mode_with_policy(other.$index, significant_figures, mode, policy)
+                    )&&+
+                }
+            }
+
+            impl<$($name: ApproxEqSfDiff),+> ApproxEqSfDiff for ($($name,)+) {
+                fn aeq_sf_mode_diff_with_policy(
+                    &self,
+                    other: Self,
+                    significant_figures: u8,
+                    mode: SfMode,
+                    policy: &SpecialValuePolicy,
+                ) -> Vec<String> {
+                    let mut mismatches = Vec::new();
+                    $(
+                        let paths =
+                            self.$index.aeq_sf_mode_diff_with_policy(other.$index, significant_figures, mode, policy);
+                        mismatches.extend(
+                            paths.iter().map(|path| join_path(stringify!($index), path)),
+                        );
+                    )+
+                    mismatches
+                }
+            }
+
+            impl<$($name: ApproxEqRounded),+> ApproxEqRounded for ($($name,)+) {
+                fn aeq_rounded_mode_with_policy(
+                    &self,
+                    other: Self,
+                    decimals: u8,
+                    mode: RoundingMode,
+                    policy: &SpecialValuePolicy,
+                ) -> bool {
+                    $(
+                        self.$index.aeq_rounded_mode_with_policy(other.$index, decimals, mode, policy)
+                    )&&+
+                }
+            }
+
+            impl<$($name: ApproxEqRel),+> ApproxEqRel for ($($name,)+) {
+                fn aeq_rel_to_with_policy(
+                    &self,
+                    other: Self,
+                    rel_tol: f64,
+                    reference: Reference,
+                    policy: &SpecialValuePolicy,
+                ) -> bool {
+                    $(
+                        self.$index.aeq_rel_to_with_policy(other.$index, rel_tol, reference, policy)
+                    )&&+
+                }
+            }
+
+            impl<$($name: ApproxEqUlps),+> ApproxEqUlps for ($($name,)+) {
+                fn aeq_ulps_with_policy(
+                    &self,
+                    other: Self,
+                    max_ulps: u32,
+                    policy: &SpecialValuePolicy,
+                ) -> bool {
+                    $(self.$index.aeq_ulps_with_policy(other.$index, max_ulps, policy))&&+
+                }
+            }
+
+            impl<$($name: IsClose),+> IsClose for ($($name,)+) {
+                fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+                    $(self.$index.is_close(other.$index, tolerance))&&+
+                }
+            }
+
+            impl<$($name: ApproxEqWith),+> ApproxEqWith for ($($name,)+) {
+                fn approx_eq_with_policy(
+                    &self,
+                    other: &Self,
+                    tolerance: &Tolerance,
+                    policy: &SpecialValuePolicy,
+                ) -> bool {
+                    $(self.$index.approx_eq_with_policy(&other.$index, tolerance, policy))&&+
+                }
+            }
+
+            crate::impl_for_pointers!(@boxes [$($name),+] ($($name,)+));
+        )+
+    };
+}
+
+tuple_impls! {
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+}
+
+/// Implements every comparison trait for a shared or borrowed pointer to any comparable `T`,
+/// taking `other`'s value out with `$into_inner`.
+macro_rules! owned_pointer_impls {
+    ($(<$lifetime:lifetime>)? $pointer:ident, $into_inner:expr $(, $unsized_bound:path)?) => {
+        impl<$($lifetime,)? T: ApproxEq + Clone> ApproxEq for $pointer<$($lifetime,)? T> {
+            fn aeq_with_policy(
+                &self,
+                other: Self,
+                decimals: u8,
+                policy: &SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_with_policy($into_inner(other), decimals, policy)
+            }
+        }
+
+        impl<$($lifetime,)? T: ApproxEqDiff + Clone> ApproxEqDiff for $pointer<$($lifetime,)? T> {
+            fn aeq_diff_with_policy(
+                &self,
+                other: Self,
+                decimals: u8,
+                policy: &SpecialValuePolicy,
+            ) -> Vec<String> {
+                (**self).aeq_diff_with_policy($into_inner(other), decimals, policy)
+            }
+        }
+
+        impl<$($lifetime,)? T: ApproxEqSf + Clone> ApproxEqSf for $pointer<$($lifetime,)? T> {
+            fn aeq_sf_mode_with_policy(
+                &self,
+                other: Self,
+                significant_figures: u8,
+                mode: SfMode,
+                policy: &SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_sf_mode_with_policy(
+                    $into_inner(other),
+                    significant_figures,
+                    mode,
+                    policy,
+                )
+            }
+        }
+
+        impl<$($lifetime,)? T: ApproxEqSfDiff + Clone> ApproxEqSfDiff for $pointer<$($lifetime,)? T> {
+            fn aeq_sf_mode_diff_with_policy(
+                &self,
+                other: Self,
+                significant_figures: u8,
+                mode: SfMode,
+                policy: &SpecialValuePolicy,
+            ) -> Vec<String> {
+                (**self).aeq_sf_mode_diff_with_policy(
+                    $into_inner(other),
+                    significant_figures,
+                    mode,
+                    policy,
+                )
+            }
+        }
+
+        impl<$($lifetime,)? T: ApproxEqRounded + Clone> ApproxEqRounded
+            for $pointer<$($lifetime,)? T>
+        {
+            fn aeq_rounded_mode_with_policy(
+                &self,
+                other: Self,
+                decimals: u8,
+                mode: RoundingMode,
+                policy: &SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_rounded_mode_with_policy($into_inner(other), decimals, mode, policy)
+            }
+        }
+
+        impl<$($lifetime,)? T: ApproxEqRel + Clone> ApproxEqRel for $pointer<$($lifetime,)? T> {
+            fn aeq_rel_to_with_policy(
+                &self,
+                other: Self,
+                rel_tol: f64,
+                reference: Reference,
+                policy: &SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_rel_to_with_policy($into_inner(other), rel_tol, reference, policy)
+            }
+        }
+
+        impl<$($lifetime,)? T: ApproxEqUlps + Clone> ApproxEqUlps for $pointer<$($lifetime,)? T> {
+            fn aeq_ulps_with_policy(
+                &self,
+                other: Self,
+                max_ulps: u32,
+                policy: &SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_ulps_with_policy($into_inner(other), max_ulps, policy)
+            }
+        }
+
+        impl<$($lifetime,)? T: IsClose + Clone> IsClose for $pointer<$($lifetime,)? T> {
+            fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+                (**self).is_close($into_inner(other), tolerance)
+            }
+        }
+
+        impl<$($lifetime,)? T: ApproxEqWith $(+ $unsized_bound)? + ?Sized> ApproxEqWith
+            for $pointer<$($lifetime,)? T>
+        {
+            fn approx_eq_with_policy(
+                &self,
+                other: &Self,
+                tolerance: &Tolerance,
+                policy: &SpecialValuePolicy,
+            ) -> bool {
+                (**self).approx_eq_with_policy(&**other, tolerance, policy)
+            }
+        }
+    };
+}
+
+owned_pointer_impls!(Rc, Rc::unwrap_or_clone);
+owned_pointer_impls!(Arc, Arc::unwrap_or_clone);
+owned_pointer_impls!(<'a> Cow, Cow::into_owned, ToOwned);
+
+/// Implements every comparison trait for references to and boxes of the listed types, by
+/// comparing the values they point to; references need the type to be `Copy`.
+///
+/// `&T` and `Box<T>` are fundamental types, so blanket impls over them would overlap the blanket
+/// impls over `FloatLike`; they are covered type by type instead. This crate invokes it for
+/// `f32`, `f64` and `Complex<F>`, and a downstream float type gets the same through
+/// `float_plus::impl_for_pointers!(MyFloat);`. Generic types list their parameters first, as in
+/// `impl_for_pointers!(<F> Complex<F>)`; each trait is only implemented where the type itself
+/// implements it.
+#[macro_export]
+macro_rules! impl_for_pointers {
+    (@references [$($generic:ident),*] $ty:ty) => {
+        impl<$($generic),*> $crate::ApproxEq for &$ty
+        where
+            $ty: $crate::ApproxEq + Copy,
+        {
+            fn aeq_with_policy(
+                &self,
+                other: Self,
+                decimals: u8,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_with_policy(*other, decimals, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::ApproxEqSf for &$ty
+        where
+            $ty: $crate::ApproxEqSf + Copy,
+        {
+            fn aeq_sf_mode_with_policy(
+                &self,
+                other: Self,
+                significant_figures: u8,
+                mode: $crate::significant_figures::approx_eq_sf::SfMode,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_sf_mode_with_policy(*other, significant_figures, mode, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::ApproxEqRounded for &$ty
+        where
+            $ty: $crate::ApproxEqRounded + Copy,
+        {
+            fn aeq_rounded_mode_with_policy(
+                &self,
+                other: Self,
+                decimals: u8,
+                mode: $crate::decimals::round_dec::RoundingMode,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_rounded_mode_with_policy(*other, decimals, mode, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::ApproxEqRel for &$ty
+        where
+            $ty: $crate::ApproxEqRel + Copy,
+        {
+            fn aeq_rel_to_with_policy(
+                &self,
+                other: Self,
+                rel_tol: f64,
+                reference: $crate::relative::approx_eq_rel::Reference,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_rel_to_with_policy(*other, rel_tol, reference, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::ApproxEqUlps for &$ty
+        where
+            $ty: $crate::ApproxEqUlps + Copy,
+        {
+            fn aeq_ulps_with_policy(
+                &self,
+                other: Self,
+                max_ulps: u32,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_ulps_with_policy(*other, max_ulps, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::IsClose for &$ty
+        where
+            $ty: $crate::IsClose + Copy,
+        {
+            fn is_close(
+                &self,
+                other: Self,
+                tolerance: &$crate::relative::is_close::IsCloseTolerance,
+            ) -> bool {
+                (**self).is_close(*other, tolerance)
+            }
+        }
+
+        impl<$($generic),*> $crate::ApproxEqWith for &$ty
+        where
+            $ty: $crate::ApproxEqWith,
+        {
+            fn approx_eq_with_policy(
+                &self,
+                other: &Self,
+                tolerance: &$crate::Tolerance,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> bool {
+                (**self).approx_eq_with_policy(*other, tolerance, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::DigitsOfAgreement for &$ty
+        where
+            $ty: $crate::DigitsOfAgreement + Copy,
+        {
+            fn digits_of_agreement_with_policy(
+                &self,
+                other: Self,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> $crate::Agreement {
+                (**self).digits_of_agreement_with_policy(*other, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::derive::ApproxEqDiff for &$ty where $ty: $crate::ApproxEq + Copy {}
+
+        impl<$($generic),*> $crate::derive::ApproxEqSfDiff for &$ty where
+            $ty: $crate::ApproxEqSf + Copy
+        {
+        }
+
+        impl<$($generic),*> $crate::assertions::AssertOperand for &$ty
+        where
+            $ty: $crate::assertions::AssertOperand,
+        {
+            fn operand(&self) -> $crate::assertions::Operand {
+                (**self).operand()
+            }
+        }
+    };
+    (@boxes [$($generic:ident),*] $ty:ty) => {
+        impl<$($generic),*> $crate::ApproxEq for $crate::alloc::boxed::Box<$ty>
+        where
+            $ty: $crate::ApproxEq,
+        {
+            fn aeq_with_policy(
+                &self,
+                other: Self,
+                decimals: u8,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_with_policy(*other, decimals, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::derive::ApproxEqDiff for $crate::alloc::boxed::Box<$ty>
+        where
+            $ty: $crate::derive::ApproxEqDiff,
+        {
+            fn aeq_diff_with_policy(
+                &self,
+                other: Self,
+                decimals: u8,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> $crate::alloc::vec::Vec<$crate::alloc::string::String> {
+                (**self).aeq_diff_with_policy(*other, decimals, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::ApproxEqSf for $crate::alloc::boxed::Box<$ty>
+        where
+            $ty: $crate::ApproxEqSf,
+        {
+            fn aeq_sf_mode_with_policy(
+                &self,
+                other: Self,
+                significant_figures: u8,
+                mode: $crate::significant_figures::approx_eq_sf::SfMode,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_sf_mode_with_policy(*other, significant_figures, mode, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::derive::ApproxEqSfDiff for $crate::alloc::boxed::Box<$ty>
+        where
+            $ty: $crate::derive::ApproxEqSfDiff,
+        {
+            fn aeq_sf_mode_diff_with_policy(
+                &self,
+                other: Self,
+                significant_figures: u8,
+                mode: $crate::significant_figures::approx_eq_sf::SfMode,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> $crate::alloc::vec::Vec<$crate::alloc::string::String> {
+                (**self).aeq_sf_mode_diff_with_policy(*other, significant_figures, mode, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::ApproxEqRounded for $crate::alloc::boxed::Box<$ty>
+        where
+            $ty: $crate::ApproxEqRounded,
+        {
+            fn aeq_rounded_mode_with_policy(
+                &self,
+                other: Self,
+                decimals: u8,
+                mode: $crate::decimals::round_dec::RoundingMode,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_rounded_mode_with_policy(*other, decimals, mode, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::ApproxEqRel for $crate::alloc::boxed::Box<$ty>
+        where
+            $ty: $crate::ApproxEqRel,
+        {
+            fn aeq_rel_to_with_policy(
+                &self,
+                other: Self,
+                rel_tol: f64,
+                reference: $crate::relative::approx_eq_rel::Reference,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_rel_to_with_policy(*other, rel_tol, reference, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::ApproxEqUlps for $crate::alloc::boxed::Box<$ty>
+        where
+            $ty: $crate::ApproxEqUlps,
+        {
+            fn aeq_ulps_with_policy(
+                &self,
+                other: Self,
+                max_ulps: u32,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> bool {
+                (**self).aeq_ulps_with_policy(*other, max_ulps, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::IsClose for $crate::alloc::boxed::Box<$ty>
+        where
+            $ty: $crate::IsClose,
+        {
+            fn is_close(
+                &self,
+                other: Self,
+                tolerance: &$crate::relative::is_close::IsCloseTolerance,
+            ) -> bool {
+                (**self).is_close(*other, tolerance)
+            }
+        }
+
+        impl<$($generic),*> $crate::ApproxEqWith for $crate::alloc::boxed::Box<$ty>
+        where
+            $ty: $crate::ApproxEqWith,
+        {
+            fn approx_eq_with_policy(
+                &self,
+                other: &Self,
+                tolerance: &$crate::Tolerance,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> bool {
+                (**self).approx_eq_with_policy(&**other, tolerance, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::DigitsOfAgreement for $crate::alloc::boxed::Box<$ty>
+        where
+            $ty: $crate::DigitsOfAgreement,
+        {
+            fn digits_of_agreement_with_policy(
+                &self,
+                other: Self,
+                policy: &$crate::SpecialValuePolicy,
+            ) -> $crate::Agreement {
+                (**self).digits_of_agreement_with_policy(*other, policy)
+            }
+        }
+
+        impl<$($generic),*> $crate::assertions::AssertOperand for $crate::alloc::boxed::Box<$ty>
+        where
+            $ty: $crate::assertions::AssertOperand,
+        {
+            fn operand(&self) -> $crate::assertions::Operand {
+                (**self).operand()
+            }
+        }
+    };
+    (<$($generic:ident),+> $ty:ty) => {
+        $crate::impl_for_pointers!(@references [$($generic),+] $ty);
+        $crate::impl_for_pointers!(@boxes [$($generic),+] $ty);
+    };
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            $crate::impl_for_pointers!(@references [] $ty);
+            $crate::impl_for_pointers!(@boxes [] $ty);
+        )+
+    };
+}
+
+impl_for_pointers!(f32, f64);
+
+#[cfg(feature = "num")]
+impl_for_pointers!(<F> num::complex::Complex<F>);
+
+// References to `Option` and `Result` compare through `as_ref`, next to their own impls, so that
+// they need no `Clone`; boxes of them are spelled out here.
+impl_for_pointers!(@boxes [T] Option<T>);
+impl_for_pointers!(@boxes [T, E] Result<T, E>);
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use anyhow::Error;
+
+    use crate::derive::{ApproxEqDiff, ApproxEqSfDiff};
+    use crate::relative::is_close::IsCloseTolerance;
+    use crate::tolerance::Tolerance;
+    use crate::{
+        ApproxEq, ApproxEqRel, ApproxEqRounded, ApproxEqSf, ApproxEqUlps, ApproxEqWith,
+        DigitsOfAgreement, IsClose, NApproxEq,
+    };
+
+    fn solve(shift: f64) -> (f64, f64, Option<f64>) {
+        (1.000_1 + shift, 2.000_1, Some(3.0))
+    }
+
+    #[test]
+    fn aeq_tuples() {
+        assert!(solve(0.0).aeq(solve(1e-5), 3));
+        assert!(solve(0.0).nae(solve(1e-2), 3));
+        assert!(solve(0.0).aeq_sf(solve(1e-9), 6));
+        assert!(solve(0.0).aeq_rounded(solve(1e-5), 3));
+        assert!(solve(0.0).aeq_rel(solve(1e-9), 1e-6));
+        assert!(solve(0.0).aeq_ulps(solve(0.0), 0));
+        assert!(solve(0.0).is_close(solve(1e-12), &IsCloseTolerance::python()));
+        assert!(solve(0.0).approx_eq(&solve(1e-5), &Tolerance::Decimals(3)));
+
+        let a = (1.0_f32, (2.0_f64, Ok::<f64, Error>(3.0)), None::<f64>);
+        let b = (1.0_f32, (2.0_f64, Ok::<f64, Error>(3.0)), None::<f64>);
+        assert!(a.aeq(b, 9));
+
+        let a = (
+            1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64, 5.0_f64, 6.0_f64, 7.0_f64, 8.0_f64, 9.0_f64,
+            10.0_f64, 11.0_f64, 12.0_f32,
+        );
+        assert!(a.aeq(a, 9));
+    }
+
+    #[test]
+    fn diff_tuples() {
+        let a = (1.0_f64, (2.0_f64, 3.0_f64));
+        let b = (1.5_f64, (2.0_f64, 3.5_f64));
+        assert_eq!(a.aeq_diff(b, 3), vec!["0", "1.1"]);
+        assert_eq!(a.aeq_sf_diff(b, 3), vec!["0", "1.1"]);
+        assert_eq!(Box::new(a).aeq_diff(Box::new(b), 3), vec!["0", "1.1"]);
+    }
+
+    #[test]
+    fn aeq_pointers() {
+        assert!(Rc::new(1.000_1_f64).aeq(Rc::new(1.000_2), 3));
+        assert!(Arc::new(Some(1.000_1_f32)).nae(Arc::new(Some(1.000_2)), 5));
+        assert!(Cow::Owned::<f64>(1.000_1).aeq_sf(Cow::Borrowed(&1.000_2), 4));
+        assert!(Box::new(1.0_f64).aeq_ulps(Box::new(1.0), 0));
+        assert!(Box::new((1.000_1_f64, Some(2.0_f32))).aeq(Box::new((1.000_2, Some(2.0))), 3));
+        let agreement =
+            Box::new(Some(Ok::<f64, Error>(1.0))).digits_of_agreement(Box::new(Some(Ok(1.0))));
+        assert_eq!(agreement.significant_figures, Some(u8::MAX));
+        assert!(Box::new(Some(1.0_f32)).aeq_rel(Box::new(Some(1.0)), 1e-9));
+        assert!(Box::new(Ok::<f64, Error>(1.0))
+            .is_close(Box::new(Ok(1.0)), &IsCloseTolerance::python()));
+        assert!(Rc::new((1.0_f64, 2.0_f64)).aeq_rounded(Rc::new((1.0, 2.0)), 3));
+
+        let a: Rc<[f64]> = Rc::from(vec![1.0, 2.0]);
+        let b: Rc<[f64]> = Rc::from(vec![1.0, 2.1]);
+        assert!(a.approx_eq(&b, &Tolerance::Abs(0.2)));
+        assert!(a.approx_ne(&b, &Tolerance::Abs(0.01)));
+
+        let a: Cow<[f64]> = Cow::Borrowed(&[1.0, 2.0]);
+        let b: Cow<[f64]> = Cow::Owned(vec![1.0, 2.0]);
+        assert!(a.approx_eq(&b, &Tolerance::Ulps(0)));
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "num")]
+pub mod approx_eq_complex_dec;
 pub mod approx_eq_dec;
+pub mod approx_eq_rounded_dec;
 pub mod not_approx_eq_dec;
 pub mod round_dec;
@@ -0,0 +1,285 @@
+use num::complex::Complex;
+use num_traits::Float;
+
+use super::approx_eq_dec::ApproxEq;
+use crate::float_like::FloatLike;
+use crate::special_values::SpecialValuePolicy;
+
+/// How two complex numbers are compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ComplexMode {
+    /// Real and imaginary parts are compared independently.
+    #[default]
+    Componentwise,
+    /// The distance `|a - b|` in the complex plane is compared.
+    Distance,
+    /// The distance relative to the modulus, `|a - b| / |a|`, is compared.
+    ///
+    /// A component that is tiny next to the modulus does not have to match on its own scale.
+    ModulusRelative,
+    /// Moduli and phases are compared separately, the phase difference wrapping around at `±π`.
+    ///
+    /// The phase is ignored when exactly one modulus is zero.
+    Polar,
+}
+
+/// Absolute phase difference of `first` and `second` in `[0, π]`.
+pub(crate) fn phase_diff<F: Float>(first: Complex<F>, second: Complex<F>) -> F {
+    (second * first.conj()).arg().abs()
+}
+
+/// Whether `first` and `second` both have a zero modulus, which every mode compares
+/// componentwise so that `SpecialValuePolicy::signed_zero_equal` decides the signs.
+pub(crate) fn both_zero<F: Float>(first: Complex<F>, second: Complex<F>) -> bool {
+    first.norm().is_zero() && second.norm().is_zero()
+}
+
+/// Whether `first` and `second` are finite in both components.
+pub(crate) fn both_finite<F: Float>(first: Complex<F>, second: Complex<F>) -> bool {
+    [first.re, first.im, second.re, second.im]
+        .iter()
+        .all(|component| component.is_finite())
+}
+
+pub trait ApproxEqComplex: Sized {
+    /// Whether both complex numbers are equal to the specified number of decimals under `mode`,
+    /// treating special values as `policy` specifies.
+    fn aeq_complex_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: ComplexMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool;
+
+    /// Whether both complex numbers are equal to the specified number of decimals under `mode`.
+    fn aeq_complex(&self, other: Self, decimals: u8, mode: ComplexMode) -> bool {
+        self.aeq_complex_with_policy(other, decimals, mode, &SpecialValuePolicy::default())
+    }
+
+    /// Negation of `aeq_complex_with_policy`.
+    fn nae_complex_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: ComplexMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        !self.aeq_complex_with_policy(other, decimals, mode, policy)
+    }
+
+    /// Negation of `aeq_complex`.
+    fn nae_complex(&self, other: Self, decimals: u8, mode: ComplexMode) -> bool {
+        !self.aeq_complex(other, decimals, mode)
+    }
+}
+
+impl<F: FloatLike> ApproxEqComplex for Complex<F> {
+    /// Non-finite components and two zeros are compared componentwise in every mode, so `policy`
+    /// decides them.
+    fn aeq_complex_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: ComplexMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if !both_finite(*self, other) || both_zero(*self, other) {
+            return self.aeq_with_policy(other, decimals, policy);
+        }
+
+        let decimals_f = F::from(decimals).unwrap();
+        let max_diff = F::from(10.0).unwrap().powf(-decimals_f);
+
+        let aeq_complex = match mode {
+            ComplexMode::Componentwise => self.aeq_with_policy(other, decimals, policy),
+            ComplexMode::Distance => (*self - other).norm() < max_diff,
+            ComplexMode::ModulusRelative => {
+                let modulus = self.norm();
+                if modulus.is_zero() {
+                    return false;
+                }
+
+                (*self - other).norm() / modulus < max_diff
+            }
+            ComplexMode::Polar => {
+                let moduli_aeq = (self.norm() - other.norm()).abs() < max_diff;
+                let either_zero = self.norm().is_zero() || other.norm().is_zero();
+
+                moduli_aeq && (either_zero || phase_diff(*self, other) < max_diff)
+            }
+        };
+
+        aeq_complex
+    }
+}
+
+impl<T: ApproxEqComplex> ApproxEqComplex for Option<T> {
+    fn aeq_complex_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: ComplexMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if self.is_none() && other.is_none() {
+            return policy.none_equal;
+        }
+
+        if self.is_some() && other.is_some() {
+            let Some(first) = self else {
+                return false;
+            };
+            let Some(second) = other else {
+                return false;
+            };
+
+            let aeq_complex = first.aeq_complex_with_policy(second, decimals, mode, policy);
+
+            return aeq_complex;
+        }
+
+        false
+    }
+}
+
+impl<T: ApproxEqComplex, E> ApproxEqComplex for Result<T, E> {
+    fn aeq_complex_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: ComplexMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if self.is_err() && other.is_err() {
+            return policy.err_equal;
+        }
+
+        if self.is_ok() && other.is_ok() {
+            let Ok(first) = self else {
+                return false;
+            };
+            let Ok(second) = other else {
+                return false;
+            };
+
+            let aeq_complex = first.aeq_complex_with_policy(second, decimals, mode, policy);
+
+            return aeq_complex;
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+    use num::complex::{Complex32, Complex64};
+
+    use super::{ApproxEqComplex, ComplexMode};
+    use crate::special_values::SpecialValuePolicy;
+    use crate::{ApproxEq, NApproxEq};
+
+    #[test]
+    fn aeq_complex_componentwise() {
+        let a = Complex64::new(1.000_1, 2.000_1);
+        let b = Complex64::new(1.000_2, 2.000_2);
+        assert!(a.aeq(b, 3));
+        assert!(a.nae(b, 5));
+        assert!(a.aeq_complex(b, 3, ComplexMode::Componentwise));
+
+        let a = Complex32::new(1.000_1, 2.000_1);
+        let b = Complex32::new(1.000_2, 2.1);
+        assert!(a.nae(b, 3));
+    }
+
+    #[test]
+    fn aeq_complex_distance() {
+        // Each component differs by 0.0008, the distance is 0.00113.
+        let a = Complex64::new(1.0, 1.0);
+        let b = Complex64::new(1.000_8, 1.000_8);
+        assert!(a.aeq_complex(b, 3, ComplexMode::Componentwise));
+        assert!(a.nae_complex(b, 3, ComplexMode::Distance));
+        assert!(a.aeq_complex(b, 2, ComplexMode::Distance));
+
+        let a = Complex32::new(1.0, 0.0);
+        let b = Complex32::new(1.0, 0.000_01);
+        assert!(a.aeq_complex(b, 4, ComplexMode::Distance));
+    }
+
+    #[test]
+    fn aeq_complex_modulus_relative_and_polar() {
+        let a = Complex64::new(1.0, 1e-17);
+        let b = Complex64::new(1.0, -2e-17);
+        assert!(a.aeq_complex(b, 12, ComplexMode::ModulusRelative));
+        assert!(a.aeq_complex(b, 12, ComplexMode::Polar));
+
+        let a = Complex64::new(1000.0, 0.0);
+        let b = Complex64::new(1000.1, 0.0);
+        assert!(a.aeq_complex(b, 3, ComplexMode::ModulusRelative));
+        assert!(a.nae_complex(b, 3, ComplexMode::Distance));
+
+        // Phases just either side of ±π are close.
+        let a = Complex64::new(-1.0, 1e-9);
+        let b = Complex64::new(-1.0, -1e-9);
+        assert!(a.aeq_complex(b, 6, ComplexMode::Polar));
+        assert!(a.nae_complex(Complex64::new(1.0, 0.0), 6, ComplexMode::Polar));
+
+        let zero = Complex64::new(0.0, 0.0);
+        assert!(zero.aeq_complex(zero, 6, ComplexMode::ModulusRelative));
+        assert!(zero.nae_complex(b, 6, ComplexMode::ModulusRelative));
+        assert!(zero.aeq_complex(Complex64::new(0.0, 1e-9), 6, ComplexMode::Polar));
+
+        // Two zeros differing in sign are decided by the policy in every mode.
+        let negative_zero = Complex64::new(-0.0, 0.0);
+        let policy = SpecialValuePolicy {
+            signed_zero_equal: false,
+            ..SpecialValuePolicy::default()
+        };
+        for mode in [
+            ComplexMode::Componentwise,
+            ComplexMode::Distance,
+            ComplexMode::ModulusRelative,
+            ComplexMode::Polar,
+        ] {
+            assert!(zero.aeq_complex(negative_zero, 6, mode));
+            assert!(zero.nae_complex_with_policy(negative_zero, 6, mode, &policy));
+            assert!(zero.aeq_complex_with_policy(zero, 6, mode, &policy));
+        }
+    }
+
+    #[test]
+    fn aeq_complex_special_values() {
+        let a = Complex64::new(f64::NAN, 1.0);
+        assert!(a.nae_complex(a, 3, ComplexMode::Distance));
+
+        let policy = SpecialValuePolicy {
+            nan_equal: true,
+            ..SpecialValuePolicy::default()
+        };
+        assert!(a.aeq_complex_with_policy(a, 3, ComplexMode::Distance, &policy));
+
+        let a = Complex64::new(f64::INFINITY, 1.0);
+        assert!(a.aeq_complex(a, 3, ComplexMode::Distance));
+    }
+
+    #[test]
+    fn aeq_complex_option_result() {
+        let a = Some(Complex64::new(1.0, 1.0));
+        let b = Some(Complex64::new(1.000_8, 1.000_8));
+        assert!(a.nae_complex(b, 3, ComplexMode::Distance));
+        assert!(None::<Complex64>.aeq_complex(None, 3, ComplexMode::Distance));
+        assert!(a.nae_complex(None, 3, ComplexMode::Distance));
+        assert!(a.aeq(b, 3));
+        assert!(None::<Complex64>.aeq(None, 3));
+
+        let a = Ok::<Complex32, Error>(Complex32::new(1.0, 1.0));
+        let b = Ok::<Complex32, Error>(Complex32::new(1.000_1, 1.0));
+        assert!(a.aeq_complex(b, 3, ComplexMode::Distance));
+
+        let a = Ok::<Complex32, Error>(Complex32::new(1.0, 1.0));
+        let b = Err(Error::msg(""));
+        assert!(a.nae(b, 3));
+    }
+}
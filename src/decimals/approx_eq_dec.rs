@@ -1,25 +1,38 @@
-use cast::f32;
-use cast::f64;
-use num_traits::abs;
+#[cfg(feature = "num")]
+use num::complex::Complex;
 
-pub trait ApproxEq {
-    fn aeq(&self, other: Self, decimals: u8) -> bool;
-}
+use crate::float_like::FloatLike;
+use crate::special_values::SpecialValuePolicy;
+
+pub trait ApproxEq: Sized {
+    /// Whether `|self - other| < 10^-decimals`, treating special values as `policy` specifies.
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool;
 
-impl ApproxEq for f64 {
+    /// Whether `|self - other| < 10^-decimals`.
     fn aeq(&self, other: Self, decimals: u8) -> bool {
-        let decimals = f64(decimals);
-        let max_diff = 10.0_f64.powf(-decimals);
+        self.aeq_with_policy(other, decimals, &SpecialValuePolicy::default())
+    }
+}
+
+impl<F: FloatLike> ApproxEq for F {
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
+        if let Some(aeq) = policy.resolve(*self, other) {
+            return aeq;
+        }
+
+        let decimals = F::from(decimals).unwrap();
+        let max_diff = F::from(10.0).unwrap().powf(-decimals);
+
+        let aeq = (*self - other).abs() < max_diff;
 
-        let aeq = abs(self - other) < max_diff;
         aeq
     }
 }
 
-impl ApproxEq for Option<f64> {
-    fn aeq(&self, other: Self, decimals: u8) -> bool {
+impl<T: ApproxEq> ApproxEq for Option<T> {
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
         if self.is_none() && other.is_none() {
-            return true;
+            return policy.none_equal;
         }
 
         if self.is_some() && other.is_some() {
@@ -30,10 +43,7 @@ impl ApproxEq for Option<f64> {
                 return false;
             };
 
-            let decimals = f64(decimals);
-            let max_diff = 10.0_f64.powf(-decimals);
-
-            let aeq = abs(first - second) < max_diff;
+            let aeq = first.aeq_with_policy(second, decimals, policy);
 
             return aeq;
         }
@@ -42,10 +52,10 @@ impl ApproxEq for Option<f64> {
     }
 }
 
-impl<E> ApproxEq for Result<f64, E> {
-    fn aeq(&self, other: Self, decimals: u8) -> bool {
+impl<T: ApproxEq, E> ApproxEq for Result<T, E> {
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
         if self.is_err() && other.is_err() {
-            return true;
+            return policy.err_equal;
         }
 
         if self.is_ok() && other.is_ok() {
@@ -56,10 +66,7 @@ impl<E> ApproxEq for Result<f64, E> {
                 return false;
             };
 
-            let decimals = f64(decimals);
-            let max_diff = 10.0_f64.powf(-decimals);
-
-            let aeq = abs(first - second) < max_diff;
+            let aeq = first.aeq_with_policy(second, decimals, policy);
 
             return aeq;
         }
@@ -68,62 +75,32 @@ impl<E> ApproxEq for Result<f64, E> {
     }
 }
 
-impl ApproxEq for f32 {
-    fn aeq(&self, other: Self, decimals: u8) -> bool {
-        let decimals = f32(decimals);
-        let max_diff = 10.0_f32.powf(-decimals);
-
-        let aeq = abs(self - other) < max_diff;
+#[cfg(feature = "num")]
+impl<F: FloatLike> ApproxEq for Complex<F> {
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
+        let real_aeq = self.re.aeq_with_policy(other.re, decimals, policy);
+        let imaginary_aeq = self.im.aeq_with_policy(other.im, decimals, policy);
+        let aeq = real_aeq && imaginary_aeq;
 
         aeq
     }
 }
 
-impl ApproxEq for Option<f32> {
-    fn aeq(&self, other: Self, decimals: u8) -> bool {
-        if self.is_none() && other.is_none() {
-            return true;
-        }
-
-        if self.is_some() && other.is_some() {
-            let first = self.unwrap();
-            let second = other.unwrap();
-
-            let decimals = f32(decimals);
-            let max_diff = 10.0_f32.powf(-decimals);
-
-            let aeq = abs(first - second) < max_diff;
-
-            return aeq;
-        }
-
-        false
+impl<'a, T> ApproxEq for &'a Option<T>
+where
+    &'a T: ApproxEq,
+{
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
+        Option::as_ref(*self).aeq_with_policy(other.as_ref(), decimals, policy)
     }
 }
 
-impl<E> ApproxEq for Result<f32, E> {
-    fn aeq(&self, other: Self, decimals: u8) -> bool {
-        if self.is_err() && other.is_err() {
-            return true;
-        }
-
-        if self.is_ok() && other.is_ok() {
-            let Ok(first) = self else {
-                return false;
-            };
-            let Ok(second) = other else {
-                return false;
-            };
-
-            let decimals = f32(decimals);
-            let max_diff = 10.0_f32.powf(-decimals);
-
-            let aeq = abs(first - second) < max_diff;
-
-            return aeq;
-        }
-
-        false
+impl<'a, T, E> ApproxEq for &'a Result<T, E>
+where
+    &'a T: ApproxEq,
+{
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
+        Result::as_ref(*self).aeq_with_policy(other.as_ref(), decimals, policy)
     }
 }
 
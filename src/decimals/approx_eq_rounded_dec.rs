@@ -0,0 +1,307 @@
+#![allow(clippy::float_cmp)]
+
+#[cfg(feature = "num")]
+use num::complex::Complex;
+
+use super::round_dec::{RoundToFraction, RoundingMode};
+use crate::float_like::FloatLike;
+use crate::special_values::SpecialValuePolicy;
+
+pub trait ApproxEqRounded: Sized {
+    /// Whether both values round to the same value at the specified number of decimals under
+    /// `mode`, treating special values as `policy` specifies.
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool;
+
+    /// Whether both values round to the same value at the specified number of decimals under
+    /// `mode`.
+    fn aeq_rounded_mode(&self, other: Self, decimals: u8, mode: RoundingMode) -> bool {
+        self.aeq_rounded_mode_with_policy(other, decimals, mode, &SpecialValuePolicy::default())
+    }
+
+    /// Whether both values round half away from zero to the same value at the specified number
+    /// of decimals, i.e. have the same `round_to_fraction(decimals)`.
+    fn aeq_rounded(&self, other: Self, decimals: u8) -> bool {
+        self.aeq_rounded_mode(other, decimals, RoundingMode::default())
+    }
+
+    /// Negation of `aeq_rounded_mode_with_policy`.
+    fn nae_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        !self.aeq_rounded_mode_with_policy(other, decimals, mode, policy)
+    }
+
+    /// Negation of `aeq_rounded_mode`.
+    fn nae_rounded_mode(&self, other: Self, decimals: u8, mode: RoundingMode) -> bool {
+        !self.aeq_rounded_mode(other, decimals, mode)
+    }
+
+    /// Negation of `aeq_rounded`.
+    fn nae_rounded(&self, other: Self, decimals: u8) -> bool {
+        !self.aeq_rounded(other, decimals)
+    }
+}
+
+/// `value` rounded to `decimals` under `mode`, or `value` itself when it has no fraction at that
+/// scale, i.e. when `|value| * 10^decimals` reaches `2^MANTISSA_DIGITS` or is not finite.
+pub(crate) fn round_or_keep<F: FloatLike>(value: F, decimals: u32, mode: RoundingMode) -> F {
+    let scale_exponent = i32::try_from(decimals).unwrap_or(i32::MAX);
+    let scaled = (value * F::from(10.0).unwrap().powi(scale_exponent)).abs();
+    let integer_limit = (F::one() + F::one()) / F::epsilon();
+
+    if !scaled.is_finite() || scaled >= integer_limit {
+        return value;
+    }
+
+    value.round_to_fraction_mode(decimals, mode)
+}
+
+impl<F: FloatLike> ApproxEqRounded for F {
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if let Some(aeq_rounded) = policy.resolve(*self, other) {
+            return aeq_rounded;
+        }
+
+        let first = round_or_keep(*self, u32::from(decimals), mode);
+        let second = round_or_keep(other, u32::from(decimals), mode);
+
+        let aeq_rounded = first == second;
+
+        aeq_rounded
+    }
+}
+
+impl<T: ApproxEqRounded> ApproxEqRounded for Option<T> {
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if self.is_none() && other.is_none() {
+            return policy.none_equal;
+        }
+
+        if self.is_some() && other.is_some() {
+            let Some(first) = self else {
+                return false;
+            };
+            let Some(second) = other else {
+                return false;
+            };
+
+            let aeq_rounded = first.aeq_rounded_mode_with_policy(second, decimals, mode, policy);
+
+            return aeq_rounded;
+        }
+
+        false
+    }
+}
+
+impl<T: ApproxEqRounded, E> ApproxEqRounded for Result<T, E> {
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if self.is_err() && other.is_err() {
+            return policy.err_equal;
+        }
+
+        if self.is_ok() && other.is_ok() {
+            let Ok(first) = self else {
+                return false;
+            };
+            let Ok(second) = other else {
+                return false;
+            };
+
+            let aeq_rounded = first.aeq_rounded_mode_with_policy(second, decimals, mode, policy);
+
+            return aeq_rounded;
+        }
+
+        false
+    }
+}
+
+#[cfg(feature = "num")]
+impl<F: FloatLike> ApproxEqRounded for Complex<F> {
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let real_aeq_rounded = self
+            .re
+            .aeq_rounded_mode_with_policy(other.re, decimals, mode, policy);
+        let imaginary_aeq_rounded = self
+            .im
+            .aeq_rounded_mode_with_policy(other.im, decimals, mode, policy);
+        let aeq_rounded = real_aeq_rounded && imaginary_aeq_rounded;
+
+        aeq_rounded
+    }
+}
+
+impl<'a, T> ApproxEqRounded for &'a Option<T>
+where
+    &'a T: ApproxEqRounded,
+{
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        Option::as_ref(*self).aeq_rounded_mode_with_policy(other.as_ref(), decimals, mode, policy)
+    }
+}
+
+impl<'a, T, E> ApproxEqRounded for &'a Result<T, E>
+where
+    &'a T: ApproxEqRounded,
+{
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        Result::as_ref(*self).aeq_rounded_mode_with_policy(other.as_ref(), decimals, mode, policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+
+    #[cfg(feature = "num")]
+    use num::complex::Complex64;
+
+    use super::ApproxEqRounded;
+    use crate::decimals::round_dec::RoundingMode;
+    use crate::ApproxEq;
+
+    #[test]
+    fn aeq_rounded_f64() {
+        let a = 1.234_4_f64;
+        let b = 1.234_6_f64;
+        assert!(a.aeq(b, 3));
+        assert!(!a.aeq_rounded(b, 3));
+        assert!(a.nae_rounded(b, 3));
+
+        let a = 1.235_1_f64;
+        let b = 1.235_4_f64;
+        assert!(a.aeq_rounded(b, 3));
+    }
+
+    #[test]
+    fn aeq_rounded_zero_decimals() {
+        assert!(!2.6_f64.aeq_rounded(2.4, 0));
+        assert!(2.6_f64.aeq_rounded(3.4, 0));
+        assert!(2.5_f64.aeq_rounded(3.0, 0));
+        assert!((-2.5_f64).aeq_rounded(-3.0, 0));
+        assert!(2.6_f64.aeq_rounded_mode(2.4, 0, RoundingMode::TowardZero));
+        assert!(2.5_f64.aeq_rounded_mode(2.0, 0, RoundingMode::HalfEven));
+    }
+
+    #[test]
+    fn aeq_rounded_large_values() {
+        assert!(!1e300_f64.aeq_rounded(1.7e300, 10));
+        assert!(1e300_f64.aeq_rounded(1e300, 10));
+        assert!(!1e20_f64.aeq_rounded(1e20 + 16_384.0, 2));
+        assert!(!f64::MAX.aeq_rounded(f64::MAX / 2.0, 255));
+        assert!(!3e38_f32.aeq_rounded(2e38, 1));
+        assert!(0.0_f64.aeq_rounded(0.000_4, 3));
+    }
+
+    #[test]
+    fn aeq_rounded_mode_f64() {
+        let a = 0.125_f64;
+        let b = 0.12_f64;
+        assert!(!a.aeq_rounded(b, 2));
+        assert!(a.aeq_rounded_mode(b, 2, RoundingMode::HalfEven));
+
+        let a = 1.239_f64;
+        let b = 1.231_f64;
+        assert!(a.aeq_rounded_mode(b, 2, RoundingMode::TowardZero));
+        assert!(a.nae_rounded_mode(b, 2, RoundingMode::HalfAwayFromZero));
+    }
+
+    #[test]
+    fn aeq_rounded_option_result_f64() {
+        let a = Some(1.235_1_f64);
+        let b = Some(1.235_4_f64);
+        assert!(a.aeq_rounded(b, 3));
+
+        let a = None::<f64>;
+        let b = None;
+        assert!(a.aeq_rounded(b, 3));
+
+        let a = Some(1.235_1_f64);
+        let b = None;
+        assert!(a.nae_rounded(b, 3));
+
+        let a = Ok::<f64, Error>(1.235_1_f64);
+        let b = Ok::<f64, Error>(1.235_4_f64);
+        assert!(a.aeq_rounded(b, 3));
+
+        let a = Ok::<f64, Error>(1.235_1_f64);
+        let b = Err(Error::msg(""));
+        assert!(a.nae_rounded(b, 3));
+    }
+
+    #[test]
+    fn aeq_rounded_f32() {
+        let a = 1.234_4_f32;
+        let b = 1.234_6_f32;
+        assert!(!a.aeq_rounded(b, 3));
+        assert!(a.aeq_rounded(b, 2));
+
+        let a = Some(1.25_f32);
+        let b = Some(1.2_f32);
+        assert!(a.aeq_rounded_mode(b, 1, RoundingMode::HalfEven));
+
+        let a = Err::<f32, Error>(Error::msg(""));
+        let b = Err(Error::msg(""));
+        assert!(a.aeq_rounded(b, 3));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn aeq_rounded_complex_f64() {
+        let a = Complex64::new(1.235_1, -2.0);
+        let b = Complex64::new(1.235_4, -2.000_4);
+        assert!(a.aeq_rounded(b, 3));
+        assert!(a.nae_rounded(b, 4));
+
+        let a = Some(Complex64::new(1.235_1, -2.0));
+        let b = None;
+        assert!(a.nae_rounded(b, 3));
+    }
+}
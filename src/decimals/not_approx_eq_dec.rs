@@ -1,132 +1,25 @@
-use cast::f32;
-use cast::f64;
-use num_traits::abs;
+use super::approx_eq_dec::ApproxEq;
+use crate::special_values::SpecialValuePolicy;
 
-pub trait NApproxEq {
-    fn nae(&self, other: Self, decimals: u8) -> bool;
-}
+/// Negation of `ApproxEq`, implemented for every type that implements it.
+pub trait NApproxEq: Sized {
+    /// Negation of `ApproxEq::aeq_with_policy`.
+    fn nae_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool;
 
-impl NApproxEq for f64 {
+    /// Negation of `ApproxEq::aeq`.
     fn nae(&self, other: Self, decimals: u8) -> bool {
-        let decimals = f64(decimals);
-        let max_diff = 10.0_f64.powf(-decimals);
-
-        let nae = abs(self - other) > max_diff;
-        nae
+        self.nae_with_policy(other, decimals, &SpecialValuePolicy::default())
     }
 }
 
-impl NApproxEq for Option<f64> {
-    fn nae(&self, other: Self, decimals: u8) -> bool {
-        if self.is_none() && other.is_none() {
-            return false;
-        }
-
-        if self.is_some() && other.is_some() {
-            let Some(first) = self else {
-                return true;
-            };
-            let Some(second) = other else {
-                return true;
-            };
-
-            let decimals = f64(decimals);
-            let max_diff = 10.0_f64.powf(-decimals);
-
-            let nae = abs(first - second) > max_diff;
-
-            return nae;
-        }
-
-        true
-    }
-}
-
-impl<E> NApproxEq for Result<f64, E> {
-    fn nae(&self, other: Self, decimals: u8) -> bool {
-        if self.is_err() && other.is_err() {
-            return false;
-        }
-
-        if self.is_ok() && other.is_ok() {
-            let Ok(first) = self else {
-                return true;
-            };
-            let Ok(second) = other else {
-                return true;
-            };
-
-            let decimals = f64(decimals);
-            let max_diff = 10.0_f64.powf(-decimals);
-
-            let nae = abs(first - second) > max_diff;
-
-            return nae;
-        }
-
-        true
-    }
-}
-
-impl NApproxEq for f32 {
-    fn nae(&self, other: Self, decimals: u8) -> bool {
-        let decimals = f32(decimals);
-        let max_diff = 10.0_f32.powf(-decimals);
-
-        let nae = abs(self - other) > max_diff;
+impl<T: ApproxEq> NApproxEq for T {
+    fn nae_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
+        let nae = !self.aeq_with_policy(other, decimals, policy);
 
         nae
     }
 }
 
-impl NApproxEq for Option<f32> {
-    fn nae(&self, other: Self, decimals: u8) -> bool {
-        if self.is_none() && other.is_none() {
-            return false;
-        }
-
-        if self.is_some() && other.is_some() {
-            let first = self.unwrap();
-            let second = other.unwrap();
-
-            let decimals = f32(decimals);
-            let max_diff = 10.0_f32.powf(-decimals);
-
-            let nae = abs(first - second) > max_diff;
-
-            return nae;
-        }
-
-        true
-    }
-}
-
-impl<E> NApproxEq for Result<f32, E> {
-    fn nae(&self, other: Self, decimals: u8) -> bool {
-        if self.is_err() && other.is_err() {
-            return false;
-        }
-
-        if self.is_ok() && other.is_ok() {
-            let Ok(first) = self else {
-                return true;
-            };
-            let Ok(second) = other else {
-                return true;
-            };
-
-            let decimals = f32(decimals);
-            let max_diff = 10.0_f32.powf(-decimals);
-
-            let nae = abs(first - second) > max_diff;
-
-            return nae;
-        }
-
-        true
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use anyhow::Error;
@@ -1,22 +1,68 @@
 use core::fmt::Debug;
 
 use num_traits::Float;
+
+/// How a value halfway between, or anywhere between, two candidates is rounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// Halfway values round away from zero.
+    #[default]
+    HalfAwayFromZero,
+    /// Halfway values round to the candidate with an even last digit.
+    HalfEven,
+    /// Round toward zero.
+    TowardZero,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+}
+
+impl RoundingMode {
+    /// Round `value` to an integer.
+    pub fn round<F: Float>(self, value: F) -> F {
+        let rounded = match self {
+            RoundingMode::HalfAwayFromZero => value.round(),
+            RoundingMode::HalfEven => {
+                let rounded = value.round();
+                let two = F::one() + F::one();
+                let is_halfway = (value - value.trunc()).abs() * two == F::one();
+                let is_odd = !(rounded / two).fract().is_zero();
+
+                if is_halfway && is_odd {
+                    rounded - value.signum()
+                } else {
+                    rounded
+                }
+            }
+            RoundingMode::TowardZero => value.trunc(),
+            RoundingMode::Floor => value.floor(),
+            RoundingMode::Ceil => value.ceil(),
+        };
+
+        rounded
+    }
+}
+
 pub trait RoundToFraction {
-    /// Round `float_number` to specified number of digits in the fraction.
+    /// Round `float_number` to specified number of digits in the fraction, halfway values away
+    /// from zero.
     fn round_to_fraction(&self, digits: u32) -> Self
     where
         Self: Float + Debug,
     {
-        let rounded_float = if digits == 0 {
-            let rounded_float = self.trunc();
-            rounded_float
-        } else {
-            let ten = Self::from(10.0).unwrap();
-            let digits = Self::from(digits).unwrap();
-            let round_factor = ten.powf(digits);
-            let rounded_float = (self.mul(round_factor)).round() / round_factor;
-            rounded_float
-        };
+        self.round_to_fraction_mode(digits, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Round `float_number` to specified number of digits in the fraction using `mode`.
+    fn round_to_fraction_mode(&self, digits: u32, mode: RoundingMode) -> Self
+    where
+        Self: Float + Debug,
+    {
+        let ten = Self::from(10.0).unwrap();
+        let digits = Self::from(digits).unwrap();
+        let round_factor = ten.powf(digits);
+        let rounded_float = mode.round(self.mul(round_factor)) / round_factor;
 
         rounded_float
     }
@@ -26,7 +72,7 @@ impl<F> RoundToFraction for F where F: Float {}
 
 #[cfg(test)]
 mod tests {
-    use super::RoundToFraction;
+    use super::{RoundToFraction, RoundingMode};
 
     #[test]
     #[allow(clippy::float_cmp)]
@@ -35,4 +81,54 @@ mod tests {
         let after = before.round_to_fraction(5);
         assert_eq!(after, 100.123_46);
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn zero_digits() {
+        assert_eq!(2.6_f64.round_to_fraction(0), 3.0);
+        assert_eq!(2.4_f64.round_to_fraction(0), 2.0);
+        assert_eq!((-2.5_f32).round_to_fraction(0), -3.0);
+        assert_eq!(
+            2.6_f64.round_to_fraction(0),
+            2.6_f64.round_to_fraction_mode(0, RoundingMode::default())
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn round_to_fraction_mode() {
+        let before = 2.5_f64;
+        assert_eq!(
+            before.round_to_fraction_mode(0, RoundingMode::HalfAwayFromZero),
+            3.0
+        );
+        assert_eq!(
+            before.round_to_fraction_mode(0, RoundingMode::HalfEven),
+            2.0
+        );
+        assert_eq!(
+            (-3.5_f64).round_to_fraction_mode(0, RoundingMode::HalfEven),
+            -4.0
+        );
+        assert_eq!(
+            (-2.5_f64).round_to_fraction_mode(0, RoundingMode::HalfEven),
+            -2.0
+        );
+
+        let before = 1.238_f64;
+        assert_eq!(
+            before.round_to_fraction_mode(2, RoundingMode::HalfAwayFromZero),
+            1.24
+        );
+        assert_eq!(
+            before.round_to_fraction_mode(2, RoundingMode::TowardZero),
+            1.23
+        );
+        assert_eq!(before.round_to_fraction_mode(2, RoundingMode::Floor), 1.23);
+        assert_eq!(
+            (-before).round_to_fraction_mode(2, RoundingMode::Floor),
+            -1.24
+        );
+        assert_eq!(before.round_to_fraction_mode(2, RoundingMode::Ceil), 1.24);
+    }
 }
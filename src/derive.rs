@@ -0,0 +1,275 @@
+//! Support code for the `ApproxEq` and `ApproxEqSf` derive macros of `float_plus_derive`.
+
+use alloc::borrow::ToOwned;
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+#[cfg(not(feature = "no_std"))]
+use std::collections::HashMap;
+
+#[cfg(feature = "num")]
+use num::complex::Complex;
+#[cfg(feature = "ordered_float")]
+use ordered_float::NotNan;
+
+#[cfg(feature = "approx")]
+use crate::bridges::approx_bridge::Approx;
+#[cfg(feature = "float_cmp")]
+use crate::bridges::float_cmp_bridge::FloatCmp;
+use crate::float_like::FloatLike;
+use crate::significant_figures::approx_eq_sf::SfMode;
+use crate::special_values::SpecialValuePolicy;
+use crate::{ApproxEq, ApproxEqSf};
+
+/// Field paths of the mismatches found by `ApproxEq`.
+///
+/// Types deriving `ApproxEq` list every mismatching field, recursing into nested fields; other
+/// types report themselves as a whole through the default methods.
+pub trait ApproxEqDiff: ApproxEq {
+    /// Paths of the fields that are not equal under `aeq_with_policy`; an empty path stands
+    /// for the value itself.
+    fn aeq_diff_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        policy: &SpecialValuePolicy,
+    ) -> Vec<String> {
+        if self.aeq_with_policy(other, decimals, policy) {
+            return Vec::new();
+        }
+
+        vec![String::new()]
+    }
+
+    /// Paths of the fields that are not equal under `aeq`.
+    fn aeq_diff(&self, other: Self, decimals: u8) -> Vec<String> {
+        self.aeq_diff_with_policy(other, decimals, &SpecialValuePolicy::default())
+    }
+}
+
+/// Field paths of the mismatches found by `ApproxEqSf`.
+///
+/// Types deriving `ApproxEqSf` list every mismatching field, recursing into nested fields; other
+/// types report themselves as a whole through the default methods.
+pub trait ApproxEqSfDiff: ApproxEqSf {
+    /// Paths of the fields that are not equal under `aeq_sf_mode_with_policy`; an empty path
+    /// stands for the value itself.
+    fn aeq_sf_mode_diff_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> Vec<String> {
+        if self.aeq_sf_mode_with_policy(other, significant_figures, mode, policy) {
+            return Vec::new();
+        }
+
+        vec![String::new()]
+    }
+
+    /// Paths of the fields that are not equal under `aeq_sf`.
+    fn aeq_sf_diff(&self, other: Self, significant_figures: u8) -> Vec<String> {
+        self.aeq_sf_mode_diff_with_policy(
+            other,
+            significant_figures,
+            SfMode::Rounded,
+            &SpecialValuePolicy::default(),
+        )
+    }
+}
+
+/// Implements `ApproxEqDiff` and `ApproxEqSfDiff` for types that are compared as a whole,
+/// wherever they implement `ApproxEq` and `ApproxEqSf`.
+macro_rules! whole_value_diff_impls {
+    ($([$($generic:tt)*] $ty:ty),+ $(,)?) => {
+        $(
+            impl<$($generic)*> ApproxEqDiff for $ty where $ty: ApproxEq {}
+
+            impl<$($generic)*> ApproxEqSfDiff for $ty where $ty: ApproxEqSf {}
+        )+
+    };
+}
+
+whole_value_diff_impls! {
+    [F: FloatLike] F,
+    [T] Option<T>,
+    [T, E] Result<T, E>,
+    ['a, T] &'a Option<T>,
+    ['a, T, E] &'a Result<T, E>,
+    ['a, T] &'a [T],
+    [T, const N: usize] [T; N],
+    [T] Vec<T>,
+    [T] VecDeque<T>,
+    [K, T] BTreeMap<K, T>,
+}
+
+#[cfg(not(feature = "no_std"))]
+whole_value_diff_impls!([K, T, S] HashMap<K, T, S>);
+
+#[cfg(feature = "num")]
+whole_value_diff_impls!([F] Complex<F>);
+
+#[cfg(feature = "ordered_float")]
+whole_value_diff_impls!([F] NotNan<F>);
+
+#[cfg(feature = "approx")]
+whole_value_diff_impls!([T] Approx<T>);
+
+#[cfg(feature = "float_cmp")]
+whole_value_diff_impls!([T] FloatCmp<T>);
+
+/// Path of a nested mismatch `path` inside the field `field`.
+#[doc(hidden)]
+pub fn join_path(field: &str, path: &str) -> String {
+    if path.is_empty() {
+        return field.to_owned();
+    }
+
+    format!("{field}.{path}")
+}
+
+/// Implemented by `#[derive(ApproxEq)]`, whose `decimals` attribute has no effect otherwise.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`#[approx(decimals = ..)]` has no effect unless `{Self}` derives `ApproxEq`",
+    label = "only read by `#[derive(ApproxEq)]`"
+)]
+pub trait DerivesApproxEq {}
+
+/// Implemented by `#[derive(ApproxEqSf)]`, whose `sf` attribute has no effect otherwise.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`#[approx(sf = ..)]` has no effect unless `{Self}` derives `ApproxEqSf`",
+    label = "only read by `#[derive(ApproxEqSf)]`"
+)]
+pub trait DerivesApproxEqSf {}
+
+#[doc(hidden)]
+pub fn assert_derives<T: ?Sized + DerivesApproxEq>() {}
+
+#[doc(hidden)]
+pub fn assert_derives_sf<T: ?Sized + DerivesApproxEqSf>() {}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApproxEqDiff, ApproxEqSfDiff};
+    use crate::{ApproxEq, ApproxEqSf, NApproxEq};
+
+    #[derive(
+        Debug, Clone, PartialEq, float_plus_derive::ApproxEq, float_plus_derive::ApproxEqSf,
+    )]
+    struct Position {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(
+        Debug, Clone, PartialEq, float_plus_derive::ApproxEq, float_plus_derive::ApproxEqSf,
+    )]
+    struct State {
+        #[approx(exact)]
+        name: String,
+        #[approx(exact)]
+        step: u32,
+        position: Position,
+        #[approx(decimals = 1, sf = 2)]
+        energy: f32,
+        #[approx(skip)]
+        scratch: Vec<f64>,
+        mass: Option<f64>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, float_plus_derive::ApproxEq)]
+    struct Pair(f64, #[approx(exact)] i32);
+
+    #[derive(
+        Debug, Clone, PartialEq, float_plus_derive::ApproxEq, float_plus_derive::ApproxEqSf,
+    )]
+    enum Shape {
+        Circle { radius: f64 },
+        Square(f64),
+        Empty,
+    }
+
+    #[derive(Debug, Clone, PartialEq, float_plus_derive::ApproxEq)]
+    struct Wrapper<T> {
+        value: T,
+    }
+
+    fn state() -> State {
+        State {
+            name: String::from("a"),
+            step: 3,
+            position: Position { x: 1.000_1, y: 2.0 },
+            energy: 10.01,
+            scratch: vec![1.0],
+            mass: Some(5.0),
+        }
+    }
+
+    #[test]
+    fn derive_struct() {
+        let a = state();
+        let mut b = state();
+        b.position.x = 1.000_2;
+        b.energy = 10.04;
+        b.scratch = vec![];
+        assert!(a.aeq(b.clone(), 3));
+        assert!(a.nae(b.clone(), 5));
+        assert!(a.aeq_sf(b.clone(), 4));
+
+        b.step = 4;
+        assert!(a.nae(b, 3));
+    }
+
+    #[test]
+    fn derive_diff() {
+        let a = state();
+        let mut b = state();
+        b.position.x = 1.1;
+        b.step = 4;
+        b.mass = None;
+
+        assert_eq!(a.aeq_diff(b.clone(), 3), vec!["step", "position.x", "mass"]);
+        assert_eq!(a.aeq_sf_diff(b, 4), vec!["step", "position.x", "mass"]);
+        assert!(a.aeq_diff(state(), 3).is_empty());
+        assert_eq!(1.0_f64.aeq_diff(2.0, 3), vec![String::new()]);
+    }
+
+    #[test]
+    fn derive_name_field_exact() {
+        let a = state();
+        let mut b = state();
+        b.name = String::from("b");
+        assert!(a.nae(b.clone(), 3));
+        assert_eq!(a.aeq_diff(b, 3), vec!["name"]);
+    }
+
+    #[test]
+    fn derive_tuple_and_generic() {
+        assert!(Pair(1.000_1, 2).aeq(Pair(1.000_2, 2), 3));
+        assert!(Pair(1.000_1, 2).nae(Pair(1.000_1, 3), 3));
+        assert_eq!(Pair(1.0, 2).aeq_diff(Pair(2.0, 3), 3), vec!["0", "1"]);
+
+        let a = Wrapper {
+            value: Some(1.000_1_f32),
+        };
+        let b = Wrapper {
+            value: Some(1.000_2_f32),
+        };
+        assert!(a.aeq(b, 3));
+    }
+
+    #[test]
+    fn derive_enum() {
+        let a = Shape::Circle { radius: 1.000_1 };
+        assert!(a.aeq(Shape::Circle { radius: 1.000_2 }, 3));
+        assert!(a.nae(Shape::Square(1.000_1), 3));
+        assert!(Shape::Empty.aeq(Shape::Empty, 3));
+        assert!(Shape::Square(123.4).aeq_sf(Shape::Square(123.0), 3));
+        assert_eq!(a.aeq_diff(Shape::Empty, 3), vec![String::new()]);
+        assert_eq!(a.aeq_diff(Shape::Circle { radius: 2.0 }, 3), vec!["radius"]);
+    }
+}
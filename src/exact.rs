@@ -0,0 +1,244 @@
+//! Comparison of floats against integers and rationals without converting them to floats.
+//!
+//! The float is expanded to its exact rational value, so `9_007_199_254_740_993_i64` is not
+//! mistaken for the nearest `f64`. NaN and the infinities are never equal to an exact value.
+
+use alloc::string::ToString;
+use core::cmp::Ordering;
+
+use num::bigint::BigInt;
+use num::rational::BigRational;
+#[cfg(feature = "num")]
+use num::rational::Ratio;
+use num_traits::float::FloatCore;
+use num_traits::{One, Signed, Zero};
+
+use crate::float_like::FloatLike;
+
+/// Exact ordering of `value` and `exact`; `None` if `value` is NaN.
+fn cmp_exact<F: FloatCore>(value: F, exact: &BigRational) -> Option<Ordering> {
+    if value.is_nan() {
+        return None;
+    }
+
+    if value.is_infinite() {
+        let ordering = if value.is_sign_positive() {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+
+        return Some(ordering);
+    }
+
+    let value = BigRational::from_float(value)?;
+
+    Some(value.cmp(exact))
+}
+
+/// `10^exponent` as an exact rational.
+fn power_of_ten(exponent: i32) -> BigRational {
+    let power = BigInt::from(10).pow(exponent.unsigned_abs());
+
+    if exponent < 0 {
+        BigRational::new(BigInt::one(), power)
+    } else {
+        BigRational::from_integer(power)
+    }
+}
+
+/// Decimal exponent of the leading significant digit of a positive rational.
+fn exponent(abs: &BigRational) -> i32 {
+    let digits = |value: &BigInt| i32::try_from(value.to_string().len()).unwrap_or(i32::MAX);
+    let mut exponent = digits(abs.numer()) - digits(abs.denom());
+
+    while power_of_ten(exponent) > *abs {
+        exponent -= 1;
+    }
+    while power_of_ten(exponent + 1) <= *abs {
+        exponent += 1;
+    }
+
+    exponent
+}
+
+/// `value` rounded half away from zero to the specified number of significant figures.
+fn round_to_sf(value: &BigRational, significant_figures: u8) -> BigRational {
+    if value.is_zero() || significant_figures == 0 {
+        return BigRational::zero();
+    }
+
+    let step = power_of_ten(exponent(&value.abs()) - i32::from(significant_figures) + 1);
+    let rounded = (value / &step).round() * step;
+
+    rounded
+}
+
+/// Whether `|value - exact| < 10^-decimals`, computed exactly.
+fn aeq_exact<F: FloatCore>(value: F, exact: &BigRational, decimals: u8) -> bool {
+    let Some(value) = BigRational::from_float(value) else {
+        return false;
+    };
+
+    let aeq = (value - exact).abs() < power_of_ten(-i32::from(decimals));
+
+    aeq
+}
+
+/// Whether `value` and `exact` round to the same value at the specified number of significant
+/// figures, computed exactly.
+fn aeq_sf_exact<F: FloatCore>(value: F, exact: &BigRational, significant_figures: u8) -> bool {
+    let Some(value) = BigRational::from_float(value) else {
+        return false;
+    };
+
+    let aeq_sf =
+        round_to_sf(&value, significant_figures) == round_to_sf(exact, significant_figures);
+
+    aeq_sf
+}
+
+/// Comparison of a float against an integer, without converting the integer to a float.
+pub trait ApproxEqInt<I> {
+    /// Exact ordering of `self` and `other`; `None` if `self` is NaN.
+    fn cmp_int(&self, other: I) -> Option<Ordering>;
+
+    /// Whether `self` is exactly `other`.
+    fn eq_int(&self, other: I) -> bool {
+        self.cmp_int(other) == Some(Ordering::Equal)
+    }
+
+    /// Whether `|self - other| < 10^-decimals`, computed exactly.
+    fn aeq_int(&self, other: I, decimals: u8) -> bool;
+
+    /// Whether both values round to the same value at the specified number of significant
+    /// figures, computed exactly.
+    fn aeq_sf_int(&self, other: I, significant_figures: u8) -> bool;
+}
+
+impl<F: FloatLike + FloatCore, I: Into<BigInt>> ApproxEqInt<I> for F {
+    fn cmp_int(&self, other: I) -> Option<Ordering> {
+        cmp_exact(*self, &BigRational::from_integer(other.into()))
+    }
+
+    fn aeq_int(&self, other: I, decimals: u8) -> bool {
+        aeq_exact(*self, &BigRational::from_integer(other.into()), decimals)
+    }
+
+    fn aeq_sf_int(&self, other: I, significant_figures: u8) -> bool {
+        aeq_sf_exact(
+            *self,
+            &BigRational::from_integer(other.into()),
+            significant_figures,
+        )
+    }
+}
+
+/// Exact value of a ratio of integers.
+#[cfg(feature = "num")]
+fn exact_ratio<T: Into<BigInt>>(ratio: Ratio<T>) -> BigRational {
+    let (numer, denom) = ratio.into_raw();
+
+    BigRational::new(numer.into(), denom.into())
+}
+
+/// Comparison of a float against a `Ratio`, without converting the ratio to a float.
+#[cfg(feature = "num")]
+pub trait ApproxEqRatio<T> {
+    /// Exact ordering of `self` and `other`; `None` if `self` is NaN.
+    fn cmp_ratio(&self, other: Ratio<T>) -> Option<Ordering>;
+
+    /// Whether `self` is exactly `other`.
+    fn eq_ratio(&self, other: Ratio<T>) -> bool {
+        self.cmp_ratio(other) == Some(Ordering::Equal)
+    }
+
+    /// Whether `|self - other| < 10^-decimals`, computed exactly.
+    fn aeq_ratio(&self, other: Ratio<T>, decimals: u8) -> bool;
+
+    /// Whether both values round to the same value at the specified number of significant
+    /// figures, computed exactly.
+    fn aeq_sf_ratio(&self, other: Ratio<T>, significant_figures: u8) -> bool;
+}
+
+#[cfg(feature = "num")]
+impl<F: FloatLike + FloatCore, T: Into<BigInt>> ApproxEqRatio<T> for F {
+    fn cmp_ratio(&self, other: Ratio<T>) -> Option<Ordering> {
+        cmp_exact(*self, &exact_ratio(other))
+    }
+
+    fn aeq_ratio(&self, other: Ratio<T>, decimals: u8) -> bool {
+        aeq_exact(*self, &exact_ratio(other), decimals)
+    }
+
+    fn aeq_sf_ratio(&self, other: Ratio<T>, significant_figures: u8) -> bool {
+        aeq_sf_exact(*self, &exact_ratio(other), significant_figures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use super::ApproxEqInt;
+
+    #[test]
+    fn beyond_f64_integer_precision() {
+        let float = 9_007_199_254_740_992_f64;
+        let exact = 9_007_199_254_740_993_i64;
+
+        assert!(float.eq_int(9_007_199_254_740_992_i64));
+        assert!(!float.eq_int(exact));
+        assert!(!float.aeq_int(exact, 0));
+        assert_eq!(float.cmp_int(exact), Some(Ordering::Less));
+
+        assert!(!float.aeq_sf_int(exact, 16));
+        assert!(float.aeq_sf_int(exact, 15));
+
+        let two_to_64 = 18_446_744_073_709_551_616_f64;
+        assert_eq!(two_to_64.cmp_int(u64::MAX), Some(Ordering::Greater));
+        assert!(two_to_64.eq_int(1_u128 << 64));
+        assert!((-two_to_64).eq_int(-(1_i128 << 64)));
+    }
+
+    #[test]
+    fn cent_totals() {
+        let total_cents = (0.1_f64 + 0.2) * 100.0;
+
+        assert!(!total_cents.eq_int(30));
+        assert!(total_cents.aeq_int(30, 9));
+        assert!(!total_cents.aeq_int(31, 1));
+        assert!(1234.4_f32.aeq_sf_int(1234_u64, 4));
+        assert!(1_234_567.0_f64.aeq_sf_int(1_234_999, 4));
+        assert!(!1_234_567.0_f64.aeq_sf_int(1_235_500, 4));
+        assert!((-1234.4_f64).aeq_sf_int(-1234, 4));
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(f64::NAN.cmp_int(0), None);
+        assert!(!f64::NAN.aeq_int(0, 0));
+        assert_eq!(f64::INFINITY.cmp_int(i128::MAX), Some(Ordering::Greater));
+        assert_eq!(f32::NEG_INFINITY.cmp_int(i128::MIN), Some(Ordering::Less));
+        assert!(!f64::INFINITY.aeq_sf_int(i64::MAX, 1));
+        assert!((-0.0_f64).eq_int(0));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn ratios() {
+        use num::rational::Ratio;
+
+        use super::ApproxEqRatio;
+
+        assert!(0.5_f64.eq_ratio(Ratio::new(1, 2)));
+        assert!(!0.1_f64.eq_ratio(Ratio::new(1, 10)));
+        assert_eq!(
+            0.1_f64.cmp_ratio(Ratio::new(1, 10)),
+            Some(Ordering::Greater)
+        );
+        assert!(0.1_f64.aeq_ratio(Ratio::new(1_i64, 10), 15));
+        assert!((1.0_f64 / 3.0).aeq_sf_ratio(Ratio::new(1_i128, 3), 15));
+        assert!(!(1.0_f32 / 3.0).aeq_sf_ratio(Ratio::new(1_u64, 3), 9));
+    }
+}
@@ -0,0 +1,107 @@
+use core::fmt::Debug;
+
+use num_traits::Float;
+
+use crate::ulps::next_float::NextFloat;
+
+/// Float type that gets every rounding and comparison trait of this crate.
+///
+/// Implemented for every `num_traits::Float` that also implements `NextFloat` and `Debug`, so a
+/// downstream float type only needs a `NextFloat` impl to get the whole API.
+pub trait FloatLike: Float + NextFloat + Debug {}
+
+impl<F> FloatLike for F where F: Float + NextFloat + Debug {}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+
+    use crate::relative::is_close::IsCloseTolerance;
+    use crate::{
+        ApproxEq, ApproxEqRel, ApproxEqRounded, ApproxEqSf, ApproxEqUlps, ApproxEqWith, IsClose,
+        NApproxEq, Tolerance,
+    };
+
+    fn all_aeq<T: ApproxEq + Copy>(pairs: &[(T, T)], decimals: u8) -> bool {
+        pairs
+            .iter()
+            .all(|(first, second)| first.aeq(*second, decimals))
+    }
+
+    #[test]
+    fn references() {
+        let a = 1.000_1_f64;
+        let b = 1.000_2_f64;
+        assert!(all_aeq(&[(&a, &b)], 3));
+        assert!(!all_aeq(&[(&a, &b)], 5));
+
+        let a = Some(1.000_1_f32);
+        let b = Some(1.000_2_f32);
+        assert!(all_aeq(&[(&a, &b)], 3));
+        assert!(<&Option<f32> as NApproxEq>::nae(&&a, &b, 5));
+        assert!(<&f64 as ApproxEqSf>::aeq_sf(&&1234.4, &1234.0, 4));
+        assert!(<&f32 as ApproxEqUlps>::aeq_ulps(&&1.0, &1.0, 0));
+        assert!(<&f64 as ApproxEqWith>::approx_eq(
+            &&1.0,
+            &&1.1,
+            &Tolerance::Abs(0.2)
+        ));
+
+        let a = Ok::<f64, Error>(1.000_1);
+        let b = Ok::<f64, Error>(1.000_2);
+        assert!(<&Result<f64, Error> as ApproxEq>::aeq(&&a, &b, 3));
+        assert!(<&Result<f64, Error> as ApproxEqRel>::aeq_rel(&&a, &b, 1e-3));
+        assert!(<&Result<f64, Error> as ApproxEqWith>::approx_eq(
+            &&a,
+            &&b,
+            &Tolerance::SigFigs(4)
+        ));
+        let c = Err::<f64, Error>(Error::msg(""));
+        assert!(<&Result<f64, Error> as NApproxEq>::nae(&&a, &c, 3));
+
+        let a = Some(Ok::<f32, Error>(1.234_4));
+        let b = Some(Ok::<f32, Error>(1.234_4));
+        assert!(<&Option<Result<f32, Error>> as ApproxEqRounded>::aeq_rounded(&&a, &b, 3));
+        assert!(<&Option<Result<f32, Error>> as IsClose>::is_close(
+            &&a,
+            &b,
+            &IsCloseTolerance::numpy()
+        ));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn complex_references() {
+        use num::complex::Complex64;
+
+        let a = Complex64::new(1.000_1, 2.0);
+        let b = Complex64::new(1.000_2, 2.0);
+        assert!(all_aeq(&[(&a, &b)], 3));
+        assert!(<&Complex64 as ApproxEqUlps>::aeq_ulps(&&a, &a, 0));
+        assert!(<&Option<Complex64> as ApproxEqSf>::aeq_sf(
+            &&Some(a),
+            &Some(b),
+            4
+        ));
+    }
+
+    #[test]
+    fn nested_wrappers() {
+        let a = Some(Ok::<f64, Error>(1.000_1));
+        let b = Some(Ok::<f64, Error>(1.000_2));
+        assert!(a.aeq(b, 3));
+
+        let a = Some(Ok::<f64, Error>(1.000_1));
+        let b = Some(Err(Error::msg("")));
+        assert!(a.nae(b, 3));
+
+        let a = Ok::<Option<f32>, Error>(None);
+        let b = Ok::<Option<f32>, Error>(None);
+        assert!(a.aeq_rel(b, 1e-9));
+
+        let a = Some(Some(1.234_4_f64));
+        let b = Some(Some(1.234_3_f64));
+        assert!(a.aeq_rounded(b, 3));
+        assert!(a.not_close(b, &IsCloseTolerance::numpy()));
+    }
+}
@@ -0,0 +1,340 @@
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::float_like::FloatLike;
+use crate::significant_figures::round_sf::{half_step, RoundToSigDig};
+
+/// Closed interval `[lo, hi]`.
+///
+/// Arithmetic rounds the lower bound down and the upper bound up, so the exact result of an
+/// operation on any members of the operands is always a member of the result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval<F> {
+    lo: F,
+    hi: F,
+}
+
+impl<F> Interval<F>
+where
+    F: FloatLike,
+{
+    /// Interval from `lo` to `hi`, or `None` if a bound is NaN or `lo > hi`.
+    pub fn new(lo: F, hi: F) -> Option<Self> {
+        if lo.is_nan() || hi.is_nan() || lo > hi {
+            return None;
+        }
+
+        Some(Self { lo, hi })
+    }
+
+    /// Interval containing only `value`, or `None` if `value` is NaN.
+    pub fn point(value: F) -> Option<Self> {
+        Self::new(value, value)
+    }
+
+    /// Interval of all values that `round_to_sf` rounds to `value` at the specified number of
+    /// significant figures, widened outward by one representable value on each side.
+    ///
+    /// The bounds lie half a unit in the last figure either side of `value`; towards zero from a
+    /// power of ten that unit is a tenth as large. Zero gets half a unit in the last figure of a
+    /// value in `[1, 10)` either side.
+    ///
+    /// Returns `None` if `value` is not finite, `significant_figures` is zero, or `value` is not
+    /// itself rounded to `significant_figures`.
+    #[allow(clippy::float_cmp)]
+    pub fn from_sf(value: F, significant_figures: u8) -> Option<Self> {
+        if !value.is_finite() || significant_figures == 0 {
+            return None;
+        }
+
+        if value.is_zero() {
+            let half_step = half_step(F::one(), significant_figures);
+            return Self::new(-half_step, half_step);
+        }
+
+        if value.round_to_sf(significant_figures) != value {
+            return None;
+        }
+
+        let away_from_zero = half_step(value, significant_figures);
+        let towards_zero = half_step(value.abs().next_down(), significant_figures);
+
+        let (below, above) = if value.is_sign_negative() {
+            (away_from_zero, towards_zero)
+        } else {
+            (towards_zero, away_from_zero)
+        };
+        let interval = Self {
+            lo: (value - below).next_down(),
+            hi: (value + above).next_up(),
+        };
+
+        Some(interval)
+    }
+
+    /// Lower bound.
+    pub fn lo(&self) -> F {
+        self.lo
+    }
+
+    /// Upper bound.
+    pub fn hi(&self) -> F {
+        self.hi
+    }
+
+    /// Distance between the bounds, rounded up.
+    pub fn width(&self) -> F {
+        (self.hi - self.lo).next_up()
+    }
+
+    /// Value halfway between the bounds.
+    pub fn midpoint(&self) -> F {
+        let half = F::one() / (F::one() + F::one());
+
+        self.lo * half + self.hi * half
+    }
+
+    /// Whether `value` lies within the bounds.
+    pub fn contains(&self, value: F) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    /// Whether the two intervals share at least one value.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.lo <= other.hi && other.lo <= self.hi
+    }
+
+    fn outward(first: F, second: F, third: F, fourth: F) -> Self {
+        let lo = first.min(second).min(third).min(fourth);
+        let hi = first.max(second).max(third).max(fourth);
+
+        Self {
+            lo: lo.next_down(),
+            hi: hi.next_up(),
+        }
+    }
+}
+
+impl<F> Add for Interval<F>
+where
+    F: FloatLike,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            lo: (self.lo + rhs.lo).next_down(),
+            hi: (self.hi + rhs.hi).next_up(),
+        }
+    }
+}
+
+impl<F> Sub for Interval<F>
+where
+    F: FloatLike,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            lo: (self.lo - rhs.hi).next_down(),
+            hi: (self.hi - rhs.lo).next_up(),
+        }
+    }
+}
+
+impl<F> Mul for Interval<F>
+where
+    F: FloatLike,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::outward(
+            self.lo * rhs.lo,
+            self.lo * rhs.hi,
+            self.hi * rhs.lo,
+            self.hi * rhs.hi,
+        )
+    }
+}
+
+impl<F> Div for Interval<F>
+where
+    F: FloatLike,
+{
+    type Output = Self;
+
+    /// Dividing by an interval that contains zero yields the whole real line.
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.contains(F::zero()) {
+            return Self {
+                lo: F::neg_infinity(),
+                hi: F::infinity(),
+            };
+        }
+
+        Self::outward(
+            self.lo / rhs.lo,
+            self.lo / rhs.hi,
+            self.hi / rhs.lo,
+            self.hi / rhs.hi,
+        )
+    }
+}
+
+impl<F> Neg for Interval<F>
+where
+    F: FloatLike,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interval;
+    use crate::significant_figures::round_sf::exponent;
+    use crate::{ApproxEqSf, RoundToSigDig};
+
+    #[test]
+    fn new_rejects_reversed_bounds() {
+        assert!(Interval::new(1.0_f64, 2.0).is_some());
+        assert!(Interval::new(2.0_f64, 1.0).is_none());
+        assert!(Interval::new(f64::NAN, 1.0).is_none());
+        assert!(Interval::point(f64::NAN).is_none());
+        assert!(Interval::point(f32::INFINITY).is_some());
+    }
+
+    #[test]
+    fn arithmetic_rounds_outward() {
+        let a = Interval::point(0.1_f64).unwrap();
+        let b = Interval::point(0.2_f64).unwrap();
+
+        let sum = a + b;
+        assert!(sum.lo() < 0.1 + 0.2);
+        assert!(sum.hi() > 0.1 + 0.2);
+
+        let difference = a - b;
+        assert!(difference.contains(0.1 - 0.2));
+
+        let product = Interval::new(-1.0_f64, 2.0).unwrap() * Interval::new(3.0, 4.0).unwrap();
+        assert!(product.lo() < -4.0);
+        assert!(product.hi() > 8.0);
+
+        let negated = -Interval::new(1.0_f32, 2.0).unwrap();
+        assert!(negated.contains(-1.5));
+        assert!(!negated.contains(1.5));
+    }
+
+    #[test]
+    fn division_by_interval_containing_zero() {
+        let one = Interval::point(1.0_f64).unwrap();
+        let quotient = one / Interval::new(-1.0, 1.0).unwrap();
+        assert!(quotient.lo().is_infinite());
+        assert!(quotient.hi().is_infinite());
+
+        let quotient = one / Interval::point(3.0).unwrap();
+        assert!(quotient.contains(1.0 / 3.0));
+        assert!(quotient.width() < 1e-15);
+    }
+
+    /// Asserts that `interval` holds exactly the sampled values that round to `value`; the
+    /// samples never land on a halfway value, which the closed interval includes.
+    #[allow(clippy::float_cmp)]
+    fn assert_matches_round_to_sf(value: f64, significant_figures: u8) {
+        let interval = Interval::from_sf(value, significant_figures).unwrap();
+        let step = 10.0_f64.powi(exponent(value) - 1);
+
+        for offset in -300..=300 {
+            let sample = value + f64::from(offset) * step / 67.0;
+            let rounds_to_value = sample.round_to_sf(significant_figures) == value;
+
+            assert_eq!(interval.contains(sample), rounds_to_value, "{sample}");
+        }
+    }
+
+    #[test]
+    fn from_sf() {
+        let interval = Interval::from_sf(123.0_f64, 3).unwrap();
+        assert!(interval.contains(122.5));
+        assert!(interval.contains(123.499_999));
+        assert!(!interval.contains(123.6));
+        assert!(!interval.contains(122.4));
+
+        assert!(Interval::from_sf(123.4_f64, 3).is_none());
+        assert!(Interval::from_sf(f64::INFINITY, 3).is_none());
+        assert!(Interval::from_sf(1.0_f64, 0).is_none());
+    }
+
+    #[test]
+    fn from_sf_at_powers_of_ten() {
+        let interval = Interval::from_sf(100.0_f64, 1).unwrap();
+        assert!(!interval.contains(60.0));
+        assert!(interval.contains(95.0));
+        assert!(!interval.contains(94.9));
+        assert!(interval.contains(149.9));
+
+        assert_matches_round_to_sf(100.0, 1);
+        assert_matches_round_to_sf(1000.0, 2);
+        assert_matches_round_to_sf(10.0, 3);
+        assert_matches_round_to_sf(1.0, 1);
+    }
+
+    #[test]
+    fn from_sf_below_one_and_negative() {
+        assert_matches_round_to_sf(0.1, 2);
+        assert_matches_round_to_sf(0.2, 2);
+        assert_matches_round_to_sf(0.012, 2);
+        assert_matches_round_to_sf(0.001_2, 2);
+        assert_matches_round_to_sf(-0.3, 3);
+        assert_matches_round_to_sf(-123.0, 3);
+        assert_matches_round_to_sf(-120.0, 3);
+        assert_matches_round_to_sf(-1000.0, 3);
+
+        let interval = Interval::from_sf(-123.0_f64, 3).unwrap();
+        assert!(interval.contains(-123.499_999) && interval.contains(-122.500_001));
+        assert!(!interval.contains(-123.6) && !interval.contains(-122.4));
+
+        let interval = Interval::from_sf(0.001_2_f64, 2).unwrap();
+        assert!(interval.contains(0.001_15) && interval.contains(0.001_249));
+        assert!(!interval.contains(0.001_14) && !interval.contains(0.001_26));
+
+        let interval = Interval::from_sf(0.012_3_f32, 3).unwrap();
+        assert!(interval.contains(0.012_25));
+        assert!(!interval.contains(0.012_36));
+        assert!(Interval::from_sf(0.012_3_f32, 2).is_none());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn from_sf_zero() {
+        let zero = Interval::from_sf(0.0_f64, 3).unwrap();
+        assert_eq!(zero.lo(), -zero.hi());
+        assert!(zero.contains(0.004_9) && zero.contains(-0.004_9));
+        assert!(!zero.contains(0.006) && !zero.contains(-0.006));
+    }
+
+    #[test]
+    fn from_sf_overlap_matches_aeq_sf() {
+        let a = 100.123_456_789_f64;
+        let b = 100.123_457;
+        let a_interval = Interval::from_sf(a.round_to_sf(9), 9).unwrap();
+        let b_interval = Interval::from_sf(b.round_to_sf(9), 9).unwrap();
+        assert!(a.aeq_sf(b, 9));
+        assert!(a_interval.contains(a) && b_interval.contains(b));
+        assert!(a_interval.overlaps(&b_interval));
+
+        let a = 100.123_454_789_f64;
+        let b = 100.123_457;
+        let a_interval = Interval::from_sf(a.round_to_sf(9), 9).unwrap();
+        let b_interval = Interval::from_sf(b.round_to_sf(9), 9).unwrap();
+        assert!(!a.aeq_sf(b, 9));
+        assert!(!a_interval.overlaps(&b_interval));
+    }
+}
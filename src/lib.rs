@@ -1,12 +1,66 @@
 #![cfg_attr(feature = "no_std", no_std)]
 
+// Lets exported macros reach `alloc` in downstream `no_std` crates.
+#[doc(hidden)]
+pub extern crate alloc;
+
+// Lets the derive macros' `::float_plus` paths resolve in this crate's own tests.
+#[cfg(test)]
+extern crate self as float_plus;
+
+pub mod agreement;
+pub mod assertions;
+pub mod bridges;
+pub mod collections;
+pub mod composites;
 pub mod decimals;
+pub mod derive;
+pub mod exact;
+pub mod float_like;
+pub mod interval;
+#[cfg(feature = "ordered_float")]
+pub mod ordered;
+pub mod ordering;
+pub mod precision;
+pub mod relative;
+pub mod rounded_key;
 pub mod significant_figures;
+pub mod special_values;
+pub mod tolerance;
+pub mod ulps;
 
+pub use agreement::{Agreement, DigitsOfAgreement};
+pub use collections::diff::{diff, DiffReport};
+pub use collections::grouping::{approx_dedup, approx_unique, cluster, Representative};
+pub use collections::search::{approx_binary_search, approx_contains, approx_position};
+#[cfg(feature = "num")]
+pub use decimals::approx_eq_complex_dec::ApproxEqComplex;
 pub use decimals::approx_eq_dec::ApproxEq;
+pub use decimals::approx_eq_rounded_dec::ApproxEqRounded;
 pub use decimals::not_approx_eq_dec::NApproxEq;
 pub use decimals::round_dec::RoundToFraction;
+pub use exact::ApproxEqInt;
+#[cfg(feature = "num")]
+pub use exact::ApproxEqRatio;
+#[cfg(feature = "derive")]
+pub use float_plus_derive::{ApproxEq, ApproxEqSf};
 
 pub use significant_figures::approx_eq_sf::ApproxEqSf;
+#[cfg(feature = "num")]
+pub use significant_figures::approx_eq_sf_complex::ApproxEqSfComplex;
 // pub use significant_figures::not_approx_eq_sf;
 pub use significant_figures::round_sf::RoundToSigDig;
+
+pub use float_like::FloatLike;
+pub use interval::Interval;
+pub use ordering::{approx_sort, approx_sort_by, ApproxOrd};
+pub use precision::{PrecisionError, PrecisionLimits, StrictPrecision, StrictRounding};
+pub use relative::approx_eq_rel::ApproxEqRel;
+pub use relative::is_close::IsClose;
+pub use rounded_key::{RoundedCmp, RoundedKey};
+pub use special_values::SpecialValuePolicy;
+pub use tolerance::approx_eq_result::ApproxEqResult;
+pub use tolerance::approx_eq_with::ApproxEqWith;
+pub use tolerance::Tolerance;
+pub use ulps::approx_eq_ulps::ApproxEqUlps;
+pub use ulps::next_float::{ulps_between, NextFloat};
@@ -0,0 +1,273 @@
+//! Support for `ordered_float::OrderedFloat` and `NotNan`.
+//!
+//! `OrderedFloat<F>` is a `FloatLike`, so every rounding and comparison trait applies to it
+//! directly and rounding returns an `OrderedFloat`. `NotNan<F>` is not a `Float`: the comparison
+//! traits delegate to the inner value and rounding goes through `TryRound`, which fails if the
+//! result is NaN.
+
+use core::cmp::Ordering;
+
+use num_traits::float::FloatCore;
+use ordered_float::{FloatIsNan, NotNan, OrderedFloat};
+
+use crate::agreement::{Agreement, DigitsOfAgreement};
+use crate::decimals::round_dec::RoundingMode;
+use crate::float_like::FloatLike;
+use crate::relative::approx_eq_rel::Reference;
+use crate::relative::is_close::IsCloseTolerance;
+use crate::rounded_key::RoundedCmp;
+use crate::significant_figures::approx_eq_sf::SfMode;
+use crate::special_values::SpecialValuePolicy;
+use crate::tolerance::Tolerance;
+use crate::{
+    ApproxEq, ApproxEqRel, ApproxEqRounded, ApproxEqSf, ApproxEqUlps, ApproxEqWith, ApproxOrd,
+    IsClose, NextFloat, RoundToFraction, RoundToSigDig,
+};
+
+impl<F: NextFloat> NextFloat for OrderedFloat<F> {
+    fn next_up(self) -> Self {
+        OrderedFloat(self.0.next_up())
+    }
+
+    fn next_down(self) -> Self {
+        OrderedFloat(self.0.next_down())
+    }
+
+    fn ulp(self) -> Self {
+        OrderedFloat(self.0.ulp())
+    }
+
+    fn ulps_between(self, other: Self) -> u64 {
+        self.0.ulps_between(other.0)
+    }
+}
+
+/// Rounding of a `NotNan` that keeps the `NotNan` guarantee.
+pub trait TryRound: Sized {
+    /// Round to `digits` digits in the fraction, or `FloatIsNan` if the result is NaN.
+    ///
+    /// # Errors
+    ///
+    /// `FloatIsNan` if rounding produced NaN, e.g. when `10^digits` overflows.
+    fn try_round_to_fraction(&self, digits: u32) -> Result<Self, FloatIsNan>;
+
+    /// Round to `digits` digits in the fraction using `mode`, or `FloatIsNan` if the result is NaN.
+    ///
+    /// # Errors
+    ///
+    /// `FloatIsNan` if rounding produced NaN.
+    fn try_round_to_fraction_mode(
+        &self,
+        digits: u32,
+        mode: RoundingMode,
+    ) -> Result<Self, FloatIsNan>;
+
+    /// Round to `significant_figures` significant figures, or `FloatIsNan` if the result is NaN.
+    ///
+    /// # Errors
+    ///
+    /// `FloatIsNan` if rounding produced NaN.
+    fn try_round_to_sf(&self, significant_figures: u8) -> Result<Self, FloatIsNan>;
+}
+
+impl<F: FloatLike + FloatCore> TryRound for NotNan<F> {
+    fn try_round_to_fraction(&self, digits: u32) -> Result<Self, FloatIsNan> {
+        NotNan::new(self.into_inner().round_to_fraction(digits))
+    }
+
+    fn try_round_to_fraction_mode(
+        &self,
+        digits: u32,
+        mode: RoundingMode,
+    ) -> Result<Self, FloatIsNan> {
+        NotNan::new(self.into_inner().round_to_fraction_mode(digits, mode))
+    }
+
+    fn try_round_to_sf(&self, significant_figures: u8) -> Result<Self, FloatIsNan> {
+        NotNan::new(self.into_inner().round_to_sf(significant_figures))
+    }
+}
+
+impl<F: FloatLike> ApproxEq for NotNan<F> {
+    fn aeq_with_policy(&self, other: Self, decimals: u8, policy: &SpecialValuePolicy) -> bool {
+        self.into_inner()
+            .aeq_with_policy(other.into_inner(), decimals, policy)
+    }
+}
+
+impl<F: FloatLike> ApproxEqSf for NotNan<F> {
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        self.into_inner().aeq_sf_mode_with_policy(
+            other.into_inner(),
+            significant_figures,
+            mode,
+            policy,
+        )
+    }
+}
+
+impl<F: FloatLike> ApproxEqRounded for NotNan<F> {
+    fn aeq_rounded_mode_with_policy(
+        &self,
+        other: Self,
+        decimals: u8,
+        mode: RoundingMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        self.into_inner()
+            .aeq_rounded_mode_with_policy(other.into_inner(), decimals, mode, policy)
+    }
+}
+
+impl<F: FloatLike> ApproxEqRel for NotNan<F> {
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        self.into_inner()
+            .aeq_rel_to_with_policy(other.into_inner(), rel_tol, reference, policy)
+    }
+}
+
+impl<F: FloatLike> ApproxEqUlps for NotNan<F> {
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        self.into_inner()
+            .aeq_ulps_with_policy(other.into_inner(), max_ulps, policy)
+    }
+}
+
+impl<F: FloatLike> IsClose for NotNan<F> {
+    fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+        self.into_inner().is_close(other.into_inner(), tolerance)
+    }
+}
+
+impl<F: FloatLike> ApproxEqWith for NotNan<F> {
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        self.into_inner()
+            .approx_eq_with_policy(&other.into_inner(), tolerance, policy)
+    }
+}
+
+impl<F: FloatLike> ApproxOrd for NotNan<F> {
+    fn acmp_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> Ordering {
+        self.into_inner()
+            .acmp_with_policy(&other.into_inner(), tolerance, policy)
+    }
+}
+
+impl<F: FloatLike> RoundedCmp for NotNan<F> {
+    fn cmp_decimals(&self, other: &Self, digits: u32) -> Ordering {
+        self.into_inner().cmp_decimals(&other.into_inner(), digits)
+    }
+
+    fn cmp_sf(&self, other: &Self, significant_figures: u8) -> Ordering {
+        self.into_inner()
+            .cmp_sf(&other.into_inner(), significant_figures)
+    }
+}
+
+impl<F: FloatLike> DigitsOfAgreement for NotNan<F> {
+    fn digits_of_agreement_with_policy(
+        &self,
+        other: Self,
+        policy: &SpecialValuePolicy,
+    ) -> Agreement {
+        self.into_inner()
+            .digits_of_agreement_with_policy(other.into_inner(), policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+    use std::collections::BTreeMap;
+
+    use ordered_float::{NotNan, OrderedFloat};
+
+    use super::TryRound;
+    use crate::decimals::round_dec::RoundingMode;
+    use crate::rounded_key::{RoundedCmp, RoundedKey};
+    use crate::tolerance::Tolerance;
+    use crate::{
+        ApproxEq, ApproxEqRel, ApproxEqSf, ApproxEqUlps, ApproxEqWith, ApproxOrd,
+        DigitsOfAgreement, RoundToFraction, RoundToSigDig,
+    };
+
+    #[test]
+    fn ordered_float() {
+        let a = OrderedFloat(1234.567_f64);
+
+        let rounded: OrderedFloat<f64> = a.round_to_sf(4);
+        assert_eq!(rounded, OrderedFloat(1235.0));
+        assert_eq!(a.round_to_fraction(1), OrderedFloat(1234.6));
+        assert!(a.aeq(OrderedFloat(1_234.567_3), 3));
+        assert!(a.aeq_sf(OrderedFloat(1235.0), 4));
+        assert!(a.aeq_rel(OrderedFloat(1234.568), 1e-6));
+        assert!(OrderedFloat(0.1_f32 + 0.2).aeq_ulps(OrderedFloat(0.3), 1));
+        assert_eq!(
+            a.acmp(&OrderedFloat(1234.0), &Tolerance::SigFigs(3)),
+            Ordering::Equal
+        );
+
+        let mut counts = BTreeMap::new();
+        for value in [1.001, 0.999, 2.0].map(OrderedFloat) {
+            *counts.entry(value.round_to_fraction(1)).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&OrderedFloat(1.0)], 2);
+
+        let key = RoundedKey::decimals(OrderedFloat(-0.000_1_f64), 2);
+        assert_eq!(key, RoundedKey::decimals(OrderedFloat(0.0), 2));
+    }
+
+    #[test]
+    fn not_nan() {
+        let a = NotNan::new(1234.567_f64).unwrap();
+        let b = NotNan::new(1234.6_f64).unwrap();
+
+        assert!(a.aeq(b, 1));
+        assert!(a.aeq_sf(b, 4));
+        assert!(a.approx_eq(&b, &Tolerance::Abs(0.1)));
+        assert!(a.approx_lt(&b, &Tolerance::Decimals(3)));
+        assert_eq!(a.digits_of_agreement(b).decimals, Some(1));
+        assert_eq!(a.cmp_sf(&b, 4), Ordering::Equal);
+        assert!(Some(a).aeq(Some(b), 1));
+
+        assert_eq!(a.try_round_to_sf(4), Ok(NotNan::new(1235.0).unwrap()));
+        assert_eq!(
+            a.try_round_to_fraction(2),
+            Ok(NotNan::new(1234.57).unwrap())
+        );
+        assert_eq!(
+            a.try_round_to_fraction_mode(0, RoundingMode::Ceil),
+            Ok(NotNan::new(1235.0).unwrap())
+        );
+
+        // The rounding factor `10^400` overflows and the result is NaN.
+        assert!(a.try_round_to_fraction(400).is_err());
+    }
+}
@@ -0,0 +1,188 @@
+use core::cmp::Ordering;
+
+use crate::float_like::FloatLike;
+use crate::special_values::SpecialValuePolicy;
+use crate::tolerance::Tolerance;
+use crate::ApproxEqWith;
+
+pub trait ApproxOrd: ApproxEqWith {
+    /// `Equal` if `self` and `other` are equal within `tolerance`, otherwise their ordering,
+    /// treating special values as `policy` specifies.
+    ///
+    /// NaN orders after every number and equal to NaN.
+    fn acmp_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> Ordering;
+
+    /// `Equal` if `self` and `other` are equal within `tolerance`, otherwise their ordering.
+    fn acmp(&self, other: &Self, tolerance: &Tolerance) -> Ordering {
+        self.acmp_with_policy(other, tolerance, &SpecialValuePolicy::default())
+    }
+
+    /// Whether `self` is less than `other` by more than `tolerance`.
+    fn approx_lt(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.acmp(other, tolerance) == Ordering::Less
+    }
+
+    /// Whether `self` is less than or equal to `other` within `tolerance`.
+    fn approx_le(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.acmp(other, tolerance) != Ordering::Greater
+    }
+
+    /// Whether `self` is greater than `other` by more than `tolerance`.
+    fn approx_gt(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.acmp(other, tolerance) == Ordering::Greater
+    }
+
+    /// Whether `self` is greater than or equal to `other` within `tolerance`.
+    fn approx_ge(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.acmp(other, tolerance) != Ordering::Less
+    }
+}
+
+impl<F: FloatLike> ApproxOrd for F {
+    fn acmp_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> Ordering {
+        if self.approx_eq_with_policy(other, tolerance, policy) {
+            return Ordering::Equal;
+        }
+
+        let ordering = self
+            .partial_cmp(other)
+            .unwrap_or_else(|| self.is_nan().cmp(&other.is_nan()));
+
+        ordering
+    }
+}
+
+/// Stable sort of `values` by `acmp`, keeping values equal within `tolerance` in their
+/// original order.
+///
+/// Approximate equality is not transitive, so the comparison is not a total order. A merge sort
+/// is used because it stays well-defined for such comparisons, where `slice::sort_by` may panic.
+pub fn approx_sort<T>(values: &mut [T], tolerance: &Tolerance)
+where
+    T: ApproxOrd + Clone,
+{
+    approx_sort_by(values, |first, second| first.acmp(second, tolerance));
+}
+
+/// Stable merge sort of `values` by `compare`, which need not be a total order.
+pub fn approx_sort_by<T, C>(values: &mut [T], mut compare: C)
+where
+    T: Clone,
+    C: FnMut(&T, &T) -> Ordering,
+{
+    merge_sort(values, &mut compare);
+}
+
+fn merge_sort<T, C>(values: &mut [T], compare: &mut C)
+where
+    T: Clone,
+    C: FnMut(&T, &T) -> Ordering,
+{
+    if values.len() < 2 {
+        return;
+    }
+
+    let middle = values.len() / 2;
+    merge_sort(&mut values[..middle], compare);
+    merge_sort(&mut values[middle..], compare);
+
+    let left = values[..middle].to_vec();
+    let right = values[middle..].to_vec();
+    let (mut left_index, mut right_index) = (0, 0);
+
+    for value in values.iter_mut() {
+        let take_right = left_index == left.len()
+            || (right_index < right.len()
+                && compare(&right[right_index], &left[left_index]) == Ordering::Less);
+
+        if take_right {
+            *value = right[right_index].clone();
+            right_index += 1;
+        } else {
+            *value = left[left_index].clone();
+            left_index += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use super::{approx_sort, approx_sort_by, ApproxOrd};
+    use crate::tolerance::Tolerance;
+
+    #[test]
+    fn acmp_f64() {
+        let tolerance = Tolerance::Decimals(3);
+        assert_eq!(1.000_1_f64.acmp(&1.000_2, &tolerance), Ordering::Equal);
+        assert_eq!(1.0_f64.acmp(&1.01, &tolerance), Ordering::Less);
+        assert_eq!(1.01_f64.acmp(&1.0, &tolerance), Ordering::Greater);
+
+        assert!(!1.000_1_f64.approx_lt(&1.000_2, &tolerance));
+        assert!(1.000_2_f64.approx_le(&1.000_1, &tolerance));
+        assert!(1.0_f64.approx_lt(&1.01, &tolerance));
+        assert!(1.01_f32.approx_gt(&1.0, &tolerance));
+        assert!(1.000_1_f32.approx_ge(&1.000_2, &tolerance));
+    }
+
+    #[test]
+    fn acmp_other_tolerances() {
+        assert_eq!(
+            1234.4_f64.acmp(&1234.0, &Tolerance::SigFigs(4)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            1e-12_f64.acmp(&2e-12, &Tolerance::Rel(1e-9)),
+            Ordering::Less
+        );
+        assert_eq!(
+            (0.1_f64 + 0.2).acmp(&0.3, &Tolerance::Ulps(1)),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn acmp_nan() {
+        let tolerance = Tolerance::Abs(1e-9);
+        assert_eq!(f64::NAN.acmp(&1.0, &tolerance), Ordering::Greater);
+        assert_eq!(1.0_f64.acmp(&f64::NAN, &tolerance), Ordering::Less);
+        assert_eq!(f64::NAN.acmp(&f64::NAN, &tolerance), Ordering::Equal);
+    }
+
+    #[test]
+    fn sort_keeps_near_equal_values_in_order() {
+        let mut values = vec![3.0_f64, 1.000_2, f64::NAN, 1.000_1, 2.0, 1.000_3];
+        approx_sort(&mut values, &Tolerance::Decimals(3));
+        assert_eq!(values[..5], [1.000_2, 1.000_1, 1.000_3, 2.0, 3.0]);
+        assert!(values[5].is_nan());
+
+        let mut pairs = vec![(2, 0.5_f64), (1, 0.100_01), (0, 0.1)];
+        approx_sort_by(&mut pairs, |first, second| {
+            first.1.acmp(&second.1, &Tolerance::Decimals(3))
+        });
+        assert_eq!(pairs, vec![(1, 0.100_01), (0, 0.1), (2, 0.5)]);
+    }
+
+    #[test]
+    fn sort_with_intransitive_comparison() {
+        // Neighbours are within tolerance of each other but the ends are not.
+        let mut values = (0..200)
+            .rev()
+            .map(|i| f64::from(i) * 6e-4)
+            .collect::<Vec<_>>();
+        approx_sort(&mut values, &Tolerance::Decimals(3));
+        assert_eq!(values.len(), 200);
+        assert!(values[0].approx_lt(&values[199], &Tolerance::Decimals(3)));
+    }
+}
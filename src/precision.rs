@@ -0,0 +1,522 @@
+//! Precision a float type can actually hold, and strict comparisons that refuse to go beyond it.
+//!
+//! `1e20_f64.aeq(1e20 + 1e4, 2)` asks for hundredths where neighbouring `f64`s are `16384` apart,
+//! so only exact equality could ever pass. The strict methods report such requests as a
+//! `PrecisionError`, and the clamped ones lower them to the feasible precision and say so.
+
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(not(feature = "no_std"))]
+use std::collections::HashMap;
+#[cfg(not(feature = "no_std"))]
+use std::hash::{BuildHasher, Hash};
+
+#[cfg(feature = "num")]
+use num::complex::Complex;
+use num_traits::Float;
+
+use crate::decimals::round_dec::RoundToFraction;
+use crate::float_like::FloatLike;
+use crate::significant_figures::round_sf::RoundToSigDig;
+use crate::{ApproxEq, ApproxEqSf};
+
+/// Requested precision that the float type cannot represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrecisionError {
+    /// More decimals than the spacing of neighbouring values resolves.
+    Decimals { requested: u32, max: i32 },
+    /// More significant figures than the type holds.
+    SignificantFigures { requested: u8, max: u8 },
+}
+
+impl fmt::Display for PrecisionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decimals { requested, max } => {
+                write!(
+                    f,
+                    "{requested} decimals requested, at most {max} are meaningful"
+                )
+            }
+            Self::SignificantFigures { requested, max } => write!(
+                f,
+                "{requested} significant figures requested, at most {max} are meaningful"
+            ),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for PrecisionError {}
+
+/// Result of an operation run at a precision lowered to what the type holds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Clamped<T> {
+    /// Result at the feasible precision.
+    pub value: T,
+    /// Whether the requested precision had to be lowered.
+    pub clamped: bool,
+}
+
+/// Significant figures needed to tell apart any two values of `F`, i.e. 17 for `f64` and 9 for
+/// `f32`.
+pub fn max_significant_figures<F: Float>() -> u8 {
+    let two = F::one() + F::one();
+    let digits = (F::one() + two.log10() - F::epsilon().log10()).ceil();
+
+    digits.to_u8().unwrap_or(u8::MAX)
+}
+
+/// Precision a value can hold.
+pub trait PrecisionLimits {
+    /// Largest `decimals` for which `10^-decimals` is not finer than the spacing of values next
+    /// to `self`; negative when not even whole units are resolved.
+    ///
+    /// Unbounded (`i32::MAX`) for NaN and the infinities, which are compared by policy.
+    fn max_decimals(&self) -> i32;
+
+    /// Significant figures needed to tell apart any two values of the type.
+    fn max_significant_figures(&self) -> u8;
+}
+
+impl<F: FloatLike> PrecisionLimits for F {
+    fn max_decimals(&self) -> i32 {
+        if !self.is_finite() {
+            return i32::MAX;
+        }
+
+        let ulp = self.abs().ulp();
+        let max_decimals = (-ulp.log10()).floor().to_i32().unwrap_or(i32::MAX);
+
+        max_decimals
+    }
+
+    fn max_significant_figures(&self) -> u8 {
+        max_significant_figures::<F>()
+    }
+}
+
+/// `decimals` if they are within `max`, or the error naming the limit.
+fn check_decimals(decimals: u32, max: i32) -> Result<u32, PrecisionError> {
+    if i64::from(decimals) > i64::from(max) {
+        return Err(PrecisionError::Decimals {
+            requested: decimals,
+            max,
+        });
+    }
+
+    Ok(decimals)
+}
+
+/// `significant_figures` if they are within `max`, or the error naming the limit.
+fn check_significant_figures(significant_figures: u8, max: u8) -> Result<u8, PrecisionError> {
+    if significant_figures > max {
+        return Err(PrecisionError::SignificantFigures {
+            requested: significant_figures,
+            max,
+        });
+    }
+
+    Ok(significant_figures)
+}
+
+/// Largest `decimals` that fit in a `u8` and are within `max`, never below `0`.
+fn feasible_decimals(decimals: u8, max: i32) -> u8 {
+    decimals.min(u8::try_from(max.max(0)).unwrap_or(u8::MAX))
+}
+
+/// Comparisons that check the requested precision against what the values hold.
+///
+/// The `_strict` methods return a `PrecisionError` for an infeasible request, the `_clamped`
+/// methods lower it to the feasible precision and flag that they did. Options, results, complex
+/// numbers and collections are limited by their least precise pair of values.
+pub trait StrictPrecision: ApproxEq + ApproxEqSf {
+    /// Most decimals both `self` and `other` resolve; unbounded (`i32::MAX`) where no pair of
+    /// finite values is compared, as the policy then decides the comparison.
+    fn pair_max_decimals(&self, other: &Self) -> i32;
+
+    /// Most significant figures both `self` and `other` hold; unbounded (`u8::MAX`) where no
+    /// pair of values is compared.
+    fn pair_max_significant_figures(&self, other: &Self) -> u8;
+
+    /// `aeq`, or an error if `10^-decimals` is finer than the spacing of the values.
+    ///
+    /// # Errors
+    ///
+    /// `PrecisionError::Decimals` if more decimals are requested than the values resolve.
+    fn aeq_strict(&self, other: Self, decimals: u8) -> Result<bool, PrecisionError> {
+        check_decimals(u32::from(decimals), self.pair_max_decimals(&other))?;
+
+        Ok(self.aeq(other, decimals))
+    }
+
+    /// `aeq` at the requested decimals or, if infeasible, at the most the values resolve.
+    ///
+    /// Values that do not even resolve whole units are compared at `0` decimals, still flagged
+    /// as clamped.
+    fn aeq_clamped(&self, other: Self, decimals: u8) -> Clamped<bool> {
+        let max = self.pair_max_decimals(&other);
+
+        Clamped {
+            value: self.aeq(other, feasible_decimals(decimals, max)),
+            clamped: i32::from(decimals) > max,
+        }
+    }
+
+    /// `aeq_sf`, or an error if the type does not hold that many significant figures.
+    ///
+    /// # Errors
+    ///
+    /// `PrecisionError::SignificantFigures` if more significant figures are requested than the
+    /// type holds.
+    fn aeq_sf_strict(&self, other: Self, significant_figures: u8) -> Result<bool, PrecisionError> {
+        check_significant_figures(
+            significant_figures,
+            self.pair_max_significant_figures(&other),
+        )?;
+
+        Ok(self.aeq_sf(other, significant_figures))
+    }
+
+    /// `aeq_sf` at the requested significant figures or, if infeasible, at the most the type
+    /// holds.
+    fn aeq_sf_clamped(&self, other: Self, significant_figures: u8) -> Clamped<bool> {
+        let feasible = significant_figures.min(self.pair_max_significant_figures(&other));
+
+        Clamped {
+            value: self.aeq_sf(other, feasible),
+            clamped: feasible < significant_figures,
+        }
+    }
+}
+
+impl<F: FloatLike> StrictPrecision for F {
+    fn pair_max_decimals(&self, other: &Self) -> i32 {
+        if !self.is_finite() || !other.is_finite() {
+            return i32::MAX;
+        }
+
+        self.max_decimals().min(other.max_decimals())
+    }
+
+    fn pair_max_significant_figures(&self, _other: &Self) -> u8 {
+        max_significant_figures::<F>()
+    }
+}
+
+impl<T: StrictPrecision> StrictPrecision for Option<T> {
+    fn pair_max_decimals(&self, other: &Self) -> i32 {
+        let (Some(first), Some(second)) = (self, other) else {
+            return i32::MAX;
+        };
+
+        first.pair_max_decimals(second)
+    }
+
+    fn pair_max_significant_figures(&self, other: &Self) -> u8 {
+        let (Some(first), Some(second)) = (self, other) else {
+            return u8::MAX;
+        };
+
+        first.pair_max_significant_figures(second)
+    }
+}
+
+impl<T: StrictPrecision, E> StrictPrecision for Result<T, E> {
+    fn pair_max_decimals(&self, other: &Self) -> i32 {
+        let (Ok(first), Ok(second)) = (self, other) else {
+            return i32::MAX;
+        };
+
+        first.pair_max_decimals(second)
+    }
+
+    fn pair_max_significant_figures(&self, other: &Self) -> u8 {
+        let (Ok(first), Ok(second)) = (self, other) else {
+            return u8::MAX;
+        };
+
+        first.pair_max_significant_figures(second)
+    }
+}
+
+#[cfg(feature = "num")]
+impl<F: FloatLike> StrictPrecision for Complex<F> {
+    fn pair_max_decimals(&self, other: &Self) -> i32 {
+        let re = self.re.pair_max_decimals(&other.re);
+        let im = self.im.pair_max_decimals(&other.im);
+
+        re.min(im)
+    }
+
+    fn pair_max_significant_figures(&self, _other: &Self) -> u8 {
+        max_significant_figures::<F>()
+    }
+}
+
+/// Implements `StrictPrecision` for collections, limited by the least precise of the pairs of
+/// elements that `$pairs` yields from `$first` and `$second`.
+macro_rules! collection_impls {
+    ($([$($generic:tt)*] $ty:ty => |$first:ident, $second:ident| $pairs:expr),+ $(,)?) => {
+        $(
+            impl<$($generic)*> StrictPrecision for $ty {
+                fn pair_max_decimals(&self, other: &Self) -> i32 {
+                    let ($first, $second) = (self, other);
+
+                    $pairs
+                        .map(|(first, second)| first.pair_max_decimals(second))
+                        .fold(i32::MAX, i32::min)
+                }
+
+                fn pair_max_significant_figures(&self, other: &Self) -> u8 {
+                    let ($first, $second) = (self, other);
+
+                    $pairs
+                        .map(|(first, second)| first.pair_max_significant_figures(second))
+                        .fold(u8::MAX, u8::min)
+                }
+            }
+        )+
+    };
+}
+
+collection_impls! {
+    ['a, T: StrictPrecision + Clone] &'a [T] => |first, second| first.iter().zip(second.iter()),
+    [T: StrictPrecision, const N: usize] [T; N] => |first, second| first.iter().zip(second),
+    [T: StrictPrecision] Vec<T> => |first, second| first.iter().zip(second),
+    [T: StrictPrecision] VecDeque<T> => |first, second| first.iter().zip(second),
+    [K: Ord, T: StrictPrecision] BTreeMap<K, T> => |first, second| {
+        first.iter().filter_map(|(key, value)| Some((value, second.get(key)?)))
+    },
+}
+
+#[cfg(not(feature = "no_std"))]
+collection_impls! {
+    [K: Eq + Hash, T: StrictPrecision, S: BuildHasher] HashMap<K, T, S> => |first, second| {
+        first.iter().filter_map(|(key, value)| Some((value, second.get(key)?)))
+    },
+}
+
+/// Rounding that checks the requested precision against what the value holds.
+pub trait StrictRounding: Sized {
+    /// `round_to_fraction`, or an error if the value does not resolve that many digits.
+    ///
+    /// # Errors
+    ///
+    /// `PrecisionError::Decimals` if more digits are requested than the value resolves.
+    fn round_to_fraction_strict(&self, digits: u32) -> Result<Self, PrecisionError>;
+
+    /// `round_to_fraction` at the requested digits or, if infeasible, at the most the value
+    /// resolves.
+    ///
+    /// Values that do not even resolve whole units are rounded to `0` digits, still flagged as
+    /// clamped.
+    fn round_to_fraction_clamped(&self, digits: u32) -> Clamped<Self>;
+
+    /// `round_to_sf`, or an error if the type does not hold that many significant figures.
+    ///
+    /// # Errors
+    ///
+    /// `PrecisionError::SignificantFigures` if more significant figures are requested than the
+    /// type holds.
+    fn round_to_sf_strict(&self, significant_figures: u8) -> Result<Self, PrecisionError>;
+
+    /// `round_to_sf` at the requested significant figures or, if infeasible, at the most the
+    /// type holds.
+    fn round_to_sf_clamped(&self, significant_figures: u8) -> Clamped<Self>;
+}
+
+impl<F: FloatLike> StrictRounding for F {
+    fn round_to_fraction_strict(&self, digits: u32) -> Result<Self, PrecisionError> {
+        check_decimals(digits, self.max_decimals())?;
+
+        Ok(self.round_to_fraction(digits))
+    }
+
+    fn round_to_fraction_clamped(&self, digits: u32) -> Clamped<Self> {
+        let max = self.max_decimals();
+        let feasible = digits.min(max.max(0).unsigned_abs());
+
+        Clamped {
+            value: self.round_to_fraction(feasible),
+            clamped: check_decimals(digits, max).is_err(),
+        }
+    }
+
+    fn round_to_sf_strict(&self, significant_figures: u8) -> Result<Self, PrecisionError> {
+        check_significant_figures(significant_figures, max_significant_figures::<F>())?;
+
+        Ok(self.round_to_sf(significant_figures))
+    }
+
+    fn round_to_sf_clamped(&self, significant_figures: u8) -> Clamped<Self> {
+        let feasible = significant_figures.min(max_significant_figures::<F>());
+
+        Clamped {
+            value: self.round_to_sf(feasible),
+            clamped: feasible < significant_figures,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{
+        max_significant_figures, Clamped, PrecisionError, PrecisionLimits, StrictPrecision,
+        StrictRounding,
+    };
+
+    #[test]
+    fn limits() {
+        assert_eq!(max_significant_figures::<f64>(), 17);
+        assert_eq!(max_significant_figures::<f32>(), 9);
+        assert_eq!(1.0_f32.max_significant_figures(), 9);
+
+        assert_eq!(1.0_f64.max_decimals(), 15);
+        assert_eq!(1.0_f32.max_decimals(), 6);
+        assert_eq!(1e20_f64.max_decimals(), -5);
+        assert_eq!(0.0_f64.max_decimals(), 323);
+        assert_eq!(f64::NAN.max_decimals(), i32::MAX);
+    }
+
+    #[test]
+    fn strict() {
+        assert_eq!(
+            1e20_f64.aeq_strict(1e20 + 1e4, 2),
+            Err(PrecisionError::Decimals {
+                requested: 2,
+                max: -5
+            })
+        );
+        assert_eq!(1.0_f64.aeq_strict(1.000_1, 3), Ok(true));
+        assert_eq!(1.0_f64.aeq_strict(-1e20, 2).map_err(|_| ()), Err(()));
+
+        assert_eq!(
+            1.0_f32.aeq_sf_strict(1.000_000_1, 12),
+            Err(PrecisionError::SignificantFigures {
+                requested: 12,
+                max: 9
+            })
+        );
+        assert_eq!(1234.4_f32.aeq_sf_strict(1234.0, 4), Ok(true));
+
+        assert_eq!(123.456_f64.round_to_fraction_strict(2), Ok(123.46));
+        assert!(1e20_f64.round_to_fraction_strict(0).is_err());
+        assert!(1.5_f32.round_to_sf_strict(10).is_err());
+        assert_eq!(f64::NAN.aeq_strict(1.0, 200), Ok(false));
+    }
+
+    #[test]
+    fn clamped() {
+        assert_eq!(
+            1e20_f64.aeq_clamped(1e20 + 1e4, 2),
+            Clamped {
+                value: false,
+                clamped: true
+            }
+        );
+        assert_eq!(
+            1.0_f64.aeq_clamped(1.0 + f64::EPSILON, 20),
+            Clamped {
+                value: true,
+                clamped: true
+            }
+        );
+        assert_eq!(
+            1.0_f64.aeq_clamped(1.000_1, 3),
+            Clamped {
+                value: true,
+                clamped: false
+            }
+        );
+
+        let aeq_sf = 1.0_f32.aeq_sf_clamped(1.000_000_1, 12);
+        assert!(aeq_sf.clamped);
+        assert!(!aeq_sf.value);
+
+        let rounded = 0.123_456_7_f32.round_to_fraction_clamped(12);
+        assert!(rounded.clamped);
+        assert!((rounded.value - 0.123_456_7).abs() < f32::EPSILON);
+        assert!(!123.456_f64.round_to_sf_clamped(4).clamped);
+        assert!(123.456_f64.round_to_sf_clamped(40).clamped);
+    }
+
+    #[test]
+    fn clamped_below_whole_units() {
+        assert_eq!(
+            1e20_f64.aeq_clamped(1e20 + 32_768.0, 2),
+            Clamped {
+                value: false,
+                clamped: true
+            }
+        );
+
+        let rounded = 1e20_f64.round_to_fraction_clamped(2);
+        assert!(rounded.clamped);
+        assert!((rounded.value - 1e20).abs() < 1.0);
+    }
+
+    #[test]
+    fn compound() {
+        assert!(Some(1e20_f64).aeq_strict(Some(1e20 + 1e4), 2).is_err());
+        assert_eq!(None::<f64>.aeq_strict(None, 200), Ok(true));
+        assert_eq!(Some(1.0_f64).aeq_strict(None, 200), Ok(false));
+        assert!(Ok::<f32, ()>(1.0).aeq_sf_strict(Ok(1.0), 12).is_err());
+        assert_eq!(Err::<f32, ()>(()).aeq_sf_strict(Err(()), 12), Ok(true));
+
+        assert_eq!(
+            vec![1.0_f64, 1e20].aeq_strict(vec![1.0, 1e20], 2),
+            Err(PrecisionError::Decimals {
+                requested: 2,
+                max: -5
+            })
+        );
+        assert_eq!(vec![1.0_f64, 2.0].aeq_strict(vec![1.0, 2.0], 2), Ok(true));
+        assert!([1.0_f32].aeq_sf_clamped([1.0], 12).clamped);
+        assert!(!Vec::<f32>::new().aeq_sf_clamped(Vec::new(), 12).clamped);
+
+        let first = BTreeMap::from([("a", 1.0_f64), ("b", 1e20)]);
+        let mut second = first.clone();
+        assert!(first.aeq_strict(second.clone(), 2).is_err());
+        second.remove("b");
+        assert_eq!(first.aeq_strict(second, 2), Ok(false));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn complex() {
+        use num::complex::{Complex32, Complex64};
+
+        let a = Complex64::new(1.0, 1e20);
+        assert_eq!(
+            a.aeq_strict(a, 2),
+            Err(PrecisionError::Decimals {
+                requested: 2,
+                max: -5
+            })
+        );
+        assert_eq!(
+            Complex64::new(1.0, 2.0).aeq_strict(Complex64::new(1.0, 2.0), 12),
+            Ok(true)
+        );
+        assert!(Complex32::new(1.0, 0.0)
+            .aeq_sf_strict(Complex32::new(1.0, 0.0), 12)
+            .is_err());
+    }
+
+    #[test]
+    fn display() {
+        let error = PrecisionError::SignificantFigures {
+            requested: 12,
+            max: 9,
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "12 significant figures requested, at most 9 are meaningful"
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod approx_eq_rel;
+pub mod is_close;
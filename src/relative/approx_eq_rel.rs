@@ -0,0 +1,313 @@
+#[cfg(feature = "num")]
+use num::complex::Complex;
+use num_traits::Float;
+
+use crate::float_like::FloatLike;
+use crate::special_values::SpecialValuePolicy;
+
+/// Magnitude that a tolerance is scaled by.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Reference {
+    /// Larger of `|a|` and `|b|`.
+    #[default]
+    Max,
+    /// Smaller of `|a|` and `|b|`.
+    Min,
+    /// Mean of `|a|` and `|b|`.
+    Mean,
+    /// `|a|`, the value being compared.
+    First,
+    /// `|b|`, the value being compared against.
+    Second,
+    /// Fixed magnitude, independent of the operands.
+    Fixed(f64),
+}
+
+impl Reference {
+    /// Magnitude of `first` and `second` under this reference.
+    pub fn magnitude<F: Float>(self, first: F, second: F) -> F {
+        let first = first.abs();
+        let second = second.abs();
+        let two = F::one() + F::one();
+
+        let magnitude = match self {
+            Reference::Max => first.max(second),
+            Reference::Min => first.min(second),
+            Reference::Mean => first / two + second / two,
+            Reference::First => first,
+            Reference::Second => second,
+            Reference::Fixed(scale) => F::from(scale.abs()).unwrap_or_else(F::nan),
+        };
+
+        magnitude
+    }
+}
+
+pub trait ApproxEqRel: Sized {
+    /// Whether `|self - other| <= rel_tol * reference` for the specified reference magnitude,
+    /// treating special values as `policy` specifies.
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool;
+
+    /// Whether `|self - other| <= rel_tol * reference` for the specified reference magnitude.
+    fn aeq_rel_to(&self, other: Self, rel_tol: f64, reference: Reference) -> bool {
+        self.aeq_rel_to_with_policy(other, rel_tol, reference, &SpecialValuePolicy::default())
+    }
+
+    /// Whether `|self - other| <= rel_tol * max(|self|, |other|)`.
+    fn aeq_rel(&self, other: Self, rel_tol: f64) -> bool {
+        self.aeq_rel_to(other, rel_tol, Reference::Max)
+    }
+
+    /// Negation of `aeq_rel_to_with_policy`.
+    fn nae_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        !self.aeq_rel_to_with_policy(other, rel_tol, reference, policy)
+    }
+
+    /// Negation of `aeq_rel_to`.
+    fn nae_rel_to(&self, other: Self, rel_tol: f64, reference: Reference) -> bool {
+        !self.aeq_rel_to(other, rel_tol, reference)
+    }
+
+    /// Negation of `aeq_rel`.
+    fn nae_rel(&self, other: Self, rel_tol: f64) -> bool {
+        !self.aeq_rel(other, rel_tol)
+    }
+}
+
+impl<F: FloatLike> ApproxEqRel for F {
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if let Some(aeq_rel) = policy.resolve(*self, other) {
+            return aeq_rel;
+        }
+
+        let rel_tol = F::from(rel_tol).unwrap();
+        let max_diff = rel_tol * reference.magnitude(*self, other);
+
+        let aeq_rel = (*self - other).abs() <= max_diff;
+
+        aeq_rel
+    }
+}
+
+impl<T: ApproxEqRel> ApproxEqRel for Option<T> {
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if self.is_none() && other.is_none() {
+            return policy.none_equal;
+        }
+
+        if self.is_some() && other.is_some() {
+            let Some(first) = self else {
+                return false;
+            };
+            let Some(second) = other else {
+                return false;
+            };
+
+            let aeq_rel = first.aeq_rel_to_with_policy(second, rel_tol, reference, policy);
+
+            return aeq_rel;
+        }
+
+        false
+    }
+}
+
+impl<T: ApproxEqRel, E> ApproxEqRel for Result<T, E> {
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if self.is_err() && other.is_err() {
+            return policy.err_equal;
+        }
+
+        if self.is_ok() && other.is_ok() {
+            let Ok(first) = self else {
+                return false;
+            };
+            let Ok(second) = other else {
+                return false;
+            };
+
+            let aeq_rel = first.aeq_rel_to_with_policy(second, rel_tol, reference, policy);
+
+            return aeq_rel;
+        }
+
+        false
+    }
+}
+
+#[cfg(feature = "num")]
+impl<F: FloatLike> ApproxEqRel for Complex<F> {
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let real_aeq_rel = self
+            .re
+            .aeq_rel_to_with_policy(other.re, rel_tol, reference, policy);
+        let imaginary_aeq_rel = self
+            .im
+            .aeq_rel_to_with_policy(other.im, rel_tol, reference, policy);
+        let aeq_rel = real_aeq_rel && imaginary_aeq_rel;
+
+        aeq_rel
+    }
+}
+
+impl<'a, T> ApproxEqRel for &'a Option<T>
+where
+    &'a T: ApproxEqRel,
+{
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        Option::as_ref(*self).aeq_rel_to_with_policy(other.as_ref(), rel_tol, reference, policy)
+    }
+}
+
+impl<'a, T, E> ApproxEqRel for &'a Result<T, E>
+where
+    &'a T: ApproxEqRel,
+{
+    fn aeq_rel_to_with_policy(
+        &self,
+        other: Self,
+        rel_tol: f64,
+        reference: Reference,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        Result::as_ref(*self).aeq_rel_to_with_policy(other.as_ref(), rel_tol, reference, policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+
+    #[cfg(feature = "num")]
+    use num::complex::Complex64;
+
+    use super::{ApproxEqRel, Reference};
+
+    #[test]
+    fn aeq_rel_f64() {
+        let a = 1.000_000_000_001e12_f64;
+        let b = 1.000_000_000_002e12_f64;
+        assert!(a.aeq_rel(b, 1e-9));
+        assert!(!a.nae_rel(b, 1e-9));
+
+        let a = 1e-12_f64;
+        let b = 2e-12_f64;
+        assert!(!a.aeq_rel(b, 1e-9));
+        assert!(a.nae_rel(b, 1e-9));
+    }
+
+    #[test]
+    fn aeq_rel_to_reference() {
+        let a = 100.0_f64;
+        let b = 110.5_f64;
+        assert!(a.aeq_rel_to(b, 0.1, Reference::Max));
+        assert!(!a.aeq_rel_to(b, 0.1, Reference::Min));
+        assert!(a.aeq_rel_to(b, 0.1, Reference::Mean));
+        assert!(a.aeq_rel_to(b, 0.1, Reference::Fixed(-110.0)));
+        assert!(!a.aeq_rel_to(b, 0.1, Reference::Fixed(99.0)));
+        assert!(!a.aeq_rel_to(b, 0.1, Reference::First));
+        assert!(a.aeq_rel_to(b, 0.1, Reference::Second));
+    }
+
+    #[test]
+    fn aeq_rel_option_f64() {
+        let a = Some(100.0_f64);
+        let b = Some(100.000_1_f64);
+        assert!(a.aeq_rel(b, 1e-6));
+        assert!(!a.aeq_rel(b, 1e-7));
+
+        let a = None::<f64>;
+        let b = None;
+        assert!(a.aeq_rel(b, 0.0));
+
+        let a = Some(100.0_f64);
+        let b = None;
+        assert!(!a.aeq_rel(b, 1.0));
+        assert!(a.nae_rel(b, 1.0));
+    }
+
+    #[test]
+    fn aeq_rel_result_f64() {
+        let a = Ok::<f64, Error>(100.0_f64);
+        let b = Ok::<f64, Error>(100.000_1_f64);
+        assert!(a.aeq_rel(b, 1e-6));
+
+        let a = Err::<f64, Error>(Error::msg(""));
+        let b = Err(Error::msg(""));
+        assert!(a.aeq_rel(b, 0.0));
+
+        let a = Ok::<f64, Error>(100.0_f64);
+        let b = Err(Error::msg(""));
+        assert!(!a.aeq_rel(b, 1.0));
+    }
+
+    #[test]
+    fn aeq_rel_f32() {
+        let a = 1.000_001e10_f32;
+        let b = 1.000_002e10_f32;
+        assert!(a.aeq_rel(b, 1e-5));
+        assert!(!a.aeq_rel(b, 1e-7));
+
+        let a = Some(1.0_f32);
+        let b = Some(1.1_f32);
+        assert!(a.nae_rel(b, 0.01));
+
+        let a = Ok::<f32, Error>(1.0_f32);
+        let b = Ok::<f32, Error>(1.1_f32);
+        assert!(a.aeq_rel(b, 0.1));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn aeq_rel_complex_f64() {
+        let a = Complex64::new(1e12, 1e-12);
+        let b = Complex64::new(1.000_000_000_001e12, 1.000_000_000_001e-12);
+        assert!(a.aeq_rel(b, 1e-9));
+
+        let a = Some(Complex64::new(1.0, 1.0));
+        let b = Some(Complex64::new(1.0, 2.0));
+        assert!(a.nae_rel(b, 0.1));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::float_like::FloatLike;
+use crate::special_values::SpecialValuePolicy;
+
+/// Tolerances and special-value handling for `IsClose::is_close`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IsCloseTolerance {
+    /// Tolerance relative to the operands' magnitude.
+    pub rel_tol: f64,
+    /// Minimum absolute tolerance, useful near zero.
+    pub abs_tol: f64,
+    /// Use `numpy.isclose`'s `|a - b| <= abs_tol + rel_tol * |b|` instead of the symmetric form.
+    pub asymmetric: bool,
+    /// Treatment of NaN, infinities and signed zeros.
+    pub special_values: SpecialValuePolicy,
+}
+
+impl IsCloseTolerance {
+    /// Defaults of Python's `math.isclose`: `rel_tol = 1e-9`, `abs_tol = 0.0`.
+    pub fn python() -> Self {
+        Self {
+            rel_tol: 1e-9,
+            abs_tol: 0.0,
+            asymmetric: false,
+            special_values: SpecialValuePolicy::default(),
+        }
+    }
+
+    /// Defaults of `numpy.isclose`: `rtol = 1e-5`, `atol = 1e-8`, asymmetric in `other`.
+    pub fn numpy() -> Self {
+        Self {
+            rel_tol: 1e-5,
+            abs_tol: 1e-8,
+            asymmetric: true,
+            special_values: SpecialValuePolicy::default(),
+        }
+    }
+}
+
+impl Default for IsCloseTolerance {
+    fn default() -> Self {
+        Self::python()
+    }
+}
+
+pub trait IsClose: Sized {
+    /// Whether `|self - other| <= max(rel_tol * max(|self|, |other|), abs_tol)`, or the
+    /// asymmetric `numpy.isclose` form if the tolerance selects it.
+    fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool;
+
+    /// Negation of `is_close`.
+    fn not_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+        !self.is_close(other, tolerance)
+    }
+}
+
+impl<F: FloatLike> IsClose for F {
+    fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+        if let Some(is_close) = tolerance.special_values.resolve(*self, other) {
+            return is_close;
+        }
+
+        let rel_tol = F::from(tolerance.rel_tol).unwrap();
+        let abs_tol = F::from(tolerance.abs_tol).unwrap();
+        let max_diff = if tolerance.asymmetric {
+            abs_tol + rel_tol * other.abs()
+        } else {
+            let rel_diff = rel_tol * self.abs().max(other.abs());
+            rel_diff.max(abs_tol)
+        };
+
+        let is_close = (*self - other).abs() <= max_diff;
+
+        is_close
+    }
+}
+
+impl<T: IsClose> IsClose for Option<T> {
+    fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+        if self.is_none() && other.is_none() {
+            return tolerance.special_values.none_equal;
+        }
+
+        if self.is_some() && other.is_some() {
+            let Some(first) = self else {
+                return false;
+            };
+            let Some(second) = other else {
+                return false;
+            };
+
+            let is_close = first.is_close(second, tolerance);
+
+            return is_close;
+        }
+
+        false
+    }
+}
+
+impl<T: IsClose, E> IsClose for Result<T, E> {
+    fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+        if self.is_err() && other.is_err() {
+            return tolerance.special_values.err_equal;
+        }
+
+        if self.is_ok() && other.is_ok() {
+            let Ok(first) = self else {
+                return false;
+            };
+            let Ok(second) = other else {
+                return false;
+            };
+
+            let is_close = first.is_close(second, tolerance);
+
+            return is_close;
+        }
+
+        false
+    }
+}
+
+impl<'a, T> IsClose for &'a Option<T>
+where
+    &'a T: IsClose,
+{
+    fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+        Option::as_ref(*self).is_close(other.as_ref(), tolerance)
+    }
+}
+
+impl<'a, T, E> IsClose for &'a Result<T, E>
+where
+    &'a T: IsClose,
+{
+    fn is_close(&self, other: Self, tolerance: &IsCloseTolerance) -> bool {
+        Result::as_ref(*self).is_close(other.as_ref(), tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+
+    use super::{IsClose, IsCloseTolerance};
+    use crate::special_values::SpecialValuePolicy;
+
+    #[test]
+    fn is_close_python() {
+        let tolerance = IsCloseTolerance::python();
+        assert!(1.0_f64.is_close(1.0 + 1e-10, &tolerance));
+        assert!(!1.0_f64.is_close(1.0 + 1e-8, &tolerance));
+        assert!(!0.0_f64.is_close(1e-300, &tolerance));
+
+        let tolerance = IsCloseTolerance {
+            abs_tol: 1e-12,
+            ..IsCloseTolerance::python()
+        };
+        assert!(0.0_f64.is_close(1e-13, &tolerance));
+        assert!(0.0_f64.not_close(1e-11, &tolerance));
+    }
+
+    #[test]
+    fn is_close_numpy_is_asymmetric() {
+        let tolerance = IsCloseTolerance {
+            rel_tol: 0.1,
+            abs_tol: 0.0,
+            ..IsCloseTolerance::numpy()
+        };
+        assert!(!10.0_f64.is_close(9.0, &tolerance));
+        assert!(9.0_f64.is_close(10.0, &tolerance));
+        assert!(1e-9_f64.is_close(0.0, &IsCloseTolerance::numpy()));
+    }
+
+    #[test]
+    fn is_close_special_values() {
+        let tolerance = IsCloseTolerance::python();
+        assert!(f64::INFINITY.is_close(f64::INFINITY, &tolerance));
+        assert!(!f64::INFINITY.is_close(f64::NEG_INFINITY, &tolerance));
+        assert!(!f64::INFINITY.is_close(f64::MAX, &tolerance));
+        assert!(!f64::NAN.is_close(f64::NAN, &tolerance));
+
+        let tolerance = IsCloseTolerance {
+            special_values: SpecialValuePolicy {
+                nan_equal: true,
+                inf_equal: false,
+                signed_zero_equal: true,
+                ..SpecialValuePolicy::default()
+            },
+            ..IsCloseTolerance::python()
+        };
+        assert!(f64::NAN.is_close(f64::NAN, &tolerance));
+        assert!(!f64::NAN.is_close(1.0, &tolerance));
+        assert!(!f64::INFINITY.is_close(f64::INFINITY, &tolerance));
+    }
+
+    #[test]
+    fn is_close_option_result_f64() {
+        let tolerance = IsCloseTolerance::python();
+
+        let a = Some(1.0_f64);
+        let b = Some(1.0 + 1e-10);
+        assert!(a.is_close(b, &tolerance));
+
+        let a = None::<f64>;
+        let b = None;
+        assert!(a.is_close(b, &tolerance));
+
+        let a = Some(1.0_f64);
+        let b = None;
+        assert!(a.not_close(b, &tolerance));
+
+        let a = Ok::<f64, Error>(1.0_f64);
+        let b = Ok::<f64, Error>(1.0 + 1e-10);
+        assert!(a.is_close(b, &tolerance));
+
+        let a = Ok::<f64, Error>(1.0_f64);
+        let b = Err(Error::msg(""));
+        assert!(a.not_close(b, &tolerance));
+    }
+
+    #[test]
+    fn is_close_f32() {
+        let tolerance = IsCloseTolerance::numpy();
+        assert!(1.0_f32.is_close(1.000_001, &tolerance));
+        assert!(!1.0_f32.is_close(1.001, &tolerance));
+
+        let a = Some(1.0_f32);
+        let b = Some(1.000_001_f32);
+        assert!(a.is_close(b, &tolerance));
+
+        let a = Err::<f32, Error>(Error::msg(""));
+        let b = Err(Error::msg(""));
+        assert!(a.is_close(b, &tolerance));
+    }
+}
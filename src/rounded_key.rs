@@ -0,0 +1,202 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use crate::decimals::approx_eq_rounded_dec::round_or_keep;
+use crate::decimals::round_dec::RoundingMode;
+use crate::float_like::FloatLike;
+use crate::RoundToSigDig;
+
+/// Float quantised at construction, usable as a `HashMap`, `HashSet` or `BTreeMap` key.
+///
+/// Keys are equal when their quantised values are. `-0.0` is stored as `0.0` and every NaN as
+/// the same NaN, which orders after every number.
+#[derive(Debug, Clone, Copy)]
+pub struct RoundedKey<F> {
+    value: F,
+}
+
+impl<F> RoundedKey<F>
+where
+    F: FloatLike,
+{
+    /// Key of `value` rounded to `digits` digits in the fraction.
+    ///
+    /// Values too large to have a fraction at that scale are kept as they are, so distinct
+    /// finite values never share an infinite key.
+    pub fn decimals(value: F, digits: u32) -> Self {
+        Self::canonical(round_or_keep(value, digits, RoundingMode::HalfAwayFromZero))
+    }
+
+    /// Key of `value` rounded to `significant_figures` significant figures.
+    pub fn sig_figs(value: F, significant_figures: u8) -> Self {
+        Self::canonical(value.round_to_sf(significant_figures))
+    }
+
+    /// Quantised value.
+    pub fn value(&self) -> F {
+        self.value
+    }
+
+    fn canonical(value: F) -> Self {
+        let value = if value.is_nan() {
+            F::nan()
+        } else if value.is_zero() {
+            F::zero()
+        } else {
+            value
+        };
+
+        Self { value }
+    }
+}
+
+impl<F: FloatLike> PartialEq for RoundedKey<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<F: FloatLike> Eq for RoundedKey<F> {}
+
+impl<F: FloatLike> PartialOrd for RoundedKey<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: FloatLike> Ord for RoundedKey<F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ordering = self
+            .value
+            .partial_cmp(&other.value)
+            .unwrap_or_else(|| self.value.is_nan().cmp(&other.value.is_nan()));
+
+        ordering
+    }
+}
+
+impl<F: FloatLike> Hash for RoundedKey<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.integer_decode().hash(state);
+    }
+}
+
+/// Approximate ordering that is a total order, so it is safe for `Ord`, sorting and map keys.
+///
+/// Values are compared after quantising both as `RoundedKey` does. Unlike `ApproxOrd::acmp`,
+/// values just either side of a rounding boundary compare unequal however close they are.
+pub trait RoundedCmp {
+    /// Ordering of the values rounded to `digits` digits in the fraction.
+    fn cmp_decimals(&self, other: &Self, digits: u32) -> Ordering;
+
+    /// Ordering of the values rounded to `significant_figures` significant figures.
+    fn cmp_sf(&self, other: &Self, significant_figures: u8) -> Ordering;
+}
+
+impl<F: FloatLike> RoundedCmp for F {
+    fn cmp_decimals(&self, other: &Self, digits: u32) -> Ordering {
+        RoundedKey::decimals(*self, digits).cmp(&RoundedKey::decimals(*other, digits))
+    }
+
+    fn cmp_sf(&self, other: &Self, significant_figures: u8) -> Ordering {
+        RoundedKey::sig_figs(*self, significant_figures)
+            .cmp(&RoundedKey::sig_figs(*other, significant_figures))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+    use std::collections::{BTreeMap, HashMap, HashSet};
+
+    use super::{RoundedCmp, RoundedKey};
+
+    #[test]
+    fn rounded_key_groups_values() {
+        let readings = [1.001_f64, 0.999_8, 2.5, 1.000_2, 2.499_9];
+        let mut groups = HashMap::<_, Vec<f64>>::new();
+        for reading in readings {
+            groups
+                .entry(RoundedKey::decimals(reading, 2))
+                .or_default()
+                .push(reading);
+        }
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&RoundedKey::decimals(1.0, 2)].len(), 3);
+        assert_eq!(groups[&RoundedKey::decimals(2.5, 2)].len(), 2);
+
+        let set = [1234.4_f32, 1234.0, 1235.6]
+            .into_iter()
+            .map(|value| RoundedKey::sig_figs(value, 4))
+            .collect::<HashSet<_>>();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn rounded_key_special_values() {
+        assert_eq!(
+            RoundedKey::decimals(-0.0_f64, 3),
+            RoundedKey::decimals(0.0, 3)
+        );
+        assert_eq!(
+            RoundedKey::decimals(-0.000_1_f64, 3),
+            RoundedKey::decimals(0.0, 3)
+        );
+        assert!(RoundedKey::decimals(-0.000_1_f64, 3)
+            .value()
+            .is_sign_positive());
+        assert_eq!(
+            RoundedKey::sig_figs(f64::NAN, 3),
+            RoundedKey::sig_figs(-f64::NAN, 3)
+        );
+
+        let set = [0.0_f64, -0.0, f64::NAN, -f64::NAN]
+            .into_iter()
+            .map(|value| RoundedKey::decimals(value, 3))
+            .collect::<HashSet<_>>();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn rounded_key_large_values() {
+        assert_ne!(
+            RoundedKey::decimals(1e305_f64, 5),
+            RoundedKey::decimals(2e305, 5)
+        );
+        assert_eq!(RoundedKey::decimals(1e305_f64, 5).value(), 1e305);
+        assert_eq!(RoundedKey::decimals(1e20_f32, 200).value(), 1e20);
+        assert_eq!(RoundedKey::decimals(1e-300_f64, 400).value(), 1e-300);
+
+        let set = [1e305_f64, 2e305, 1e305]
+            .into_iter()
+            .map(|value| RoundedKey::decimals(value, 5))
+            .collect::<HashSet<_>>();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn rounded_key_orders() {
+        let mut map = BTreeMap::new();
+        for value in [f64::NAN, 2.0, f64::NEG_INFINITY, -0.0, 1.004] {
+            map.insert(RoundedKey::decimals(value, 2), value);
+        }
+
+        let keys = map.keys().map(RoundedKey::value).collect::<Vec<_>>();
+        assert_eq!(keys[..4], [f64::NEG_INFINITY, 0.0, 1.0, 2.0]);
+        assert!(keys[4].is_nan());
+    }
+
+    #[test]
+    fn rounded_cmp() {
+        assert_eq!(1.000_4_f64.cmp_decimals(&1.0, 3), Ordering::Equal);
+        assert_eq!(1.000_6_f64.cmp_decimals(&1.0, 3), Ordering::Greater);
+        assert_eq!(1234.4_f32.cmp_sf(&1234.0, 4), Ordering::Equal);
+        assert_eq!(f64::NAN.cmp_sf(&1.0, 4), Ordering::Greater);
+
+        let mut values = vec![2.0_f64, 1.000_4, 1.0, 0.5];
+        values.sort_by(|first, second| first.cmp_decimals(second, 3));
+        assert_eq!(values, vec![0.5, 1.000_4, 1.0, 2.0]);
+    }
+}
@@ -1,3 +1,5 @@
 pub mod approx_eq_sf;
+#[cfg(feature = "num")]
+pub mod approx_eq_sf_complex;
 pub mod not_approx_eq_sf;
 pub mod round_sf;
@@ -1,143 +1,123 @@
 #![allow(clippy::float_cmp)]
 
 #[cfg(feature = "num")]
-use num::complex::{Complex32, Complex64};
-
-use super::round_sf::RoundToSigDig;
-
-pub trait ApproxEqSf {
-    fn aeq_sf(&self, other: Self, significant_figures: u8) -> bool;
+use num::complex::Complex;
+use num_traits::Float;
+
+use super::round_sf::{half_step, RoundToSigDig};
+use crate::float_like::FloatLike;
+use crate::relative::approx_eq_rel::Reference;
+use crate::special_values::SpecialValuePolicy;
+
+/// How `ApproxEqSf` decides that two values agree to a number of significant figures.
+///
+/// Both modes count figures from the leading digit of the magnitude, so neither the sign nor the
+/// zeros after the decimal point of a value below one are figures.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SfMode {
+    /// Both values round to the same value.
+    #[default]
+    Rounded,
+    /// `|a - b| <= 0.5 * 10^(exponent - sf + 1)`, with the exponent of the reference magnitude.
+    ///
+    /// Values either side of a rounding boundary compare equal when they are close.
+    Distance(Reference),
 }
 
-impl ApproxEqSf for f64 {
-    fn aeq_sf(&self, other: Self, significant_figures: u8) -> bool {
-        let first = self.round_to_sf(significant_figures);
-        let second = other.round_to_sf(significant_figures);
-
-        let aeq_sf = first == second;
-
-        aeq_sf
+pub trait ApproxEqSf: Sized {
+    /// Whether both values agree to the specified number of significant figures under `mode`,
+    /// treating special values as `policy` specifies.
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool;
+
+    /// Whether both values agree to the specified number of significant figures under `mode`.
+    fn aeq_sf_mode(&self, other: Self, significant_figures: u8, mode: SfMode) -> bool {
+        self.aeq_sf_mode_with_policy(
+            other,
+            significant_figures,
+            mode,
+            &SpecialValuePolicy::default(),
+        )
     }
-}
 
-impl ApproxEqSf for Option<f64> {
-    fn aeq_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_none() && other.is_none() {
-            return true;
-        }
-
-        if self.is_some() && other.is_some() {
-            let Some(first) = self else {
-                return false;
-            };
-            let Some(second) = other else {
-                return false;
-            };
-
-            let first = first.round_to_sf(significant_figures);
-            let second = second.round_to_sf(significant_figures);
-            let aeq_sf = first == second;
-
-            return aeq_sf;
-        }
-
-        false
+    /// Whether both values round to the same value at the specified number of significant
+    /// figures, treating special values as `policy` specifies.
+    fn aeq_sf_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        self.aeq_sf_mode_with_policy(other, significant_figures, SfMode::Rounded, policy)
     }
-}
 
-#[cfg(feature = "num")]
-impl ApproxEqSf for Complex64 {
+    /// Whether both values round to the same value at the specified number of significant figures.
     fn aeq_sf(&self, other: Self, significant_figures: u8) -> bool {
-        let real_aeq_sf = self.re.aeq_sf(other.re, significant_figures);
-        let imaginary_aeq_sf = self.im.aeq_sf(other.im, significant_figures);
-        let aeq_sf = real_aeq_sf && imaginary_aeq_sf;
-
-        aeq_sf
+        self.aeq_sf_with_policy(other, significant_figures, &SpecialValuePolicy::default())
     }
 }
 
-#[cfg(feature = "num")]
-impl ApproxEqSf for Option<Complex64> {
-    fn aeq_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_some() && other.is_some() {
-            let first = self.unwrap();
-            let second = other.unwrap();
-            let real_aeq_sf = first.re.aeq_sf(second.re, significant_figures);
-            let imaginary_aeq_sf = first.im.aeq_sf(second.im, significant_figures);
-            let aeq_sf = real_aeq_sf && imaginary_aeq_sf;
-
-            return aeq_sf;
-        }
-
-        if self.is_none() && other.is_none() {
-            return true;
-        }
-
-        false
+/// Whether `first` and `second` are within half a unit in the last significant figure of the
+/// reference magnitude.
+fn within_half_step<F: Float>(
+    first: F,
+    second: F,
+    significant_figures: u8,
+    reference: Reference,
+) -> bool {
+    let magnitude = reference.magnitude(first, second);
+    if magnitude.is_zero() {
+        return first == second;
     }
-}
-
-#[cfg(feature = "num")]
-impl<E> ApproxEqSf for Result<Complex64, E> {
-    fn aeq_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_ok() && other.is_ok() {
-            let first = unsafe { self.as_ref().unwrap_unchecked() };
-            let second = unsafe { other.as_ref().unwrap_unchecked() };
-            let real_aeq_sf = first.re.aeq_sf(second.re, significant_figures);
-            let imaginary_aeq_sf = first.im.aeq_sf(second.im, significant_figures);
-            let aeq_sf = real_aeq_sf && imaginary_aeq_sf;
 
-            return aeq_sf;
-        }
-
-        if self.is_err() && other.is_err() {
-            return true;
-        }
+    let within_half_step = (first - second).abs() <= half_step(magnitude, significant_figures);
 
-        false
-    }
+    within_half_step
 }
 
-impl<E> ApproxEqSf for Result<f64, E> {
-    fn aeq_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_err() && other.is_err() {
-            return true;
-        }
-
-        if self.is_ok() && other.is_ok() {
-            let Ok(first) = self else {
-                return false;
-            };
-            let Ok(second) = other else {
-                return false;
-            };
-
-            let first = first.round_to_sf(significant_figures);
-            let second = second.round_to_sf(significant_figures);
-            let aeq_sf = first == second;
-
+impl<F: FloatLike> ApproxEqSf for F {
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if let Some(aeq_sf) = policy.resolve(*self, other) {
             return aeq_sf;
         }
 
-        false
-    }
-}
+        let aeq_sf = match mode {
+            SfMode::Rounded => {
+                let first = self.round_to_sf(significant_figures);
+                let second = other.round_to_sf(significant_figures);
 
-impl ApproxEqSf for f32 {
-    fn aeq_sf(&self, other: Self, significant_figures: u8) -> bool {
-        let first = self.round_to_sf(significant_figures);
-        let second = other.round_to_sf(significant_figures);
+                first == second
+            }
+            SfMode::Distance(reference) => {
+                within_half_step(*self, other, significant_figures, reference)
+            }
+        };
 
-        let aeq = first == second;
-
-        aeq
+        aeq_sf
     }
 }
 
-impl ApproxEqSf for Option<f32> {
-    fn aeq_sf(&self, other: Self, significant_figures: u8) -> bool {
+impl<T: ApproxEqSf> ApproxEqSf for Option<T> {
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
         if self.is_none() && other.is_none() {
-            return true;
+            return policy.none_equal;
         }
 
         if self.is_some() && other.is_some() {
@@ -148,9 +128,7 @@ impl ApproxEqSf for Option<f32> {
                 return false;
             };
 
-            let first = first.round_to_sf(significant_figures);
-            let second = second.round_to_sf(significant_figures);
-            let aeq_sf = first == second;
+            let aeq_sf = first.aeq_sf_mode_with_policy(second, significant_figures, mode, policy);
 
             return aeq_sf;
         }
@@ -159,10 +137,16 @@ impl ApproxEqSf for Option<f32> {
     }
 }
 
-impl<E> ApproxEqSf for Result<f32, E> {
-    fn aeq_sf(&self, other: Self, significant_figures: u8) -> bool {
+impl<T: ApproxEqSf, E> ApproxEqSf for Result<T, E> {
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
         if self.is_err() && other.is_err() {
-            return true;
+            return policy.err_equal;
         }
 
         if self.is_ok() && other.is_ok() {
@@ -173,9 +157,7 @@ impl<E> ApproxEqSf for Result<f32, E> {
                 return false;
             };
 
-            let first = first.round_to_sf(significant_figures);
-            let second = second.round_to_sf(significant_figures);
-            let aeq_sf = first == second;
+            let aeq_sf = first.aeq_sf_mode_with_policy(second, significant_figures, mode, policy);
 
             return aeq_sf;
         }
@@ -185,55 +167,63 @@ impl<E> ApproxEqSf for Result<f32, E> {
 }
 
 #[cfg(feature = "num")]
-impl ApproxEqSf for Complex32 {
-    fn aeq_sf(&self, other: Self, significant_figures: u8) -> bool {
-        let real_aeq_sf = self.re.aeq_sf(other.re, significant_figures);
-        let imaginary_aeq_sf = self.im.aeq_sf(other.im, significant_figures);
+impl<F: FloatLike> ApproxEqSf for Complex<F> {
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let real_aeq_sf =
+            self.re
+                .aeq_sf_mode_with_policy(other.re, significant_figures, mode, policy);
+        let imaginary_aeq_sf =
+            self.im
+                .aeq_sf_mode_with_policy(other.im, significant_figures, mode, policy);
         let aeq_sf = real_aeq_sf && imaginary_aeq_sf;
 
         aeq_sf
     }
 }
 
-#[cfg(feature = "num")]
-impl ApproxEqSf for Option<Complex32> {
-    fn aeq_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_some() && other.is_some() {
-            let first = self.unwrap();
-            let second = other.unwrap();
-            let real_aeq_sf = first.re.aeq_sf(second.re, significant_figures);
-            let imaginary_aeq_sf = first.im.aeq_sf(second.im, significant_figures);
-            let aeq_sf = real_aeq_sf && imaginary_aeq_sf;
-
-            return aeq_sf;
-        }
-
-        if self.is_none() && other.is_none() {
-            return true;
-        }
-
-        false
+impl<'a, T> ApproxEqSf for &'a Option<T>
+where
+    &'a T: ApproxEqSf,
+{
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        Option::as_ref(*self).aeq_sf_mode_with_policy(
+            other.as_ref(),
+            significant_figures,
+            mode,
+            policy,
+        )
     }
 }
 
-#[cfg(feature = "num")]
-impl<E> ApproxEqSf for Result<Complex32, E> {
-    fn aeq_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_ok() && other.is_ok() {
-            let first = unsafe { self.as_ref().unwrap_unchecked() };
-            let second = unsafe { other.as_ref().unwrap_unchecked() };
-            let real_aeq_sf = first.re.aeq_sf(second.re, significant_figures);
-            let imaginary_aeq_sf = first.im.aeq_sf(second.im, significant_figures);
-            let aeq_sf = real_aeq_sf && imaginary_aeq_sf;
-
-            return aeq_sf;
-        }
-
-        if self.is_err() && other.is_err() {
-            return true;
-        }
-
-        false
+impl<'a, T, E> ApproxEqSf for &'a Result<T, E>
+where
+    &'a T: ApproxEqSf,
+{
+    fn aeq_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        Result::as_ref(*self).aeq_sf_mode_with_policy(
+            other.as_ref(),
+            significant_figures,
+            mode,
+            policy,
+        )
     }
 }
 
@@ -241,7 +231,8 @@ impl<E> ApproxEqSf for Result<Complex32, E> {
 mod tests {
     use anyhow::Error;
 
-    use crate::significant_figures::approx_eq_sf::ApproxEqSf;
+    use crate::relative::approx_eq_rel::Reference;
+    use crate::significant_figures::approx_eq_sf::{ApproxEqSf, SfMode};
 
     #[test]
     fn aeq_sf_f64() {
@@ -341,4 +332,55 @@ mod tests {
         let b = Err(Error::msg("message"));
         assert!(a.aeq_sf(b, 4));
     }
+
+    #[test]
+    fn aeq_sf_distance_mode() {
+        let a = 99.944_999_f64;
+        let b = 99.945_001_f64;
+        assert!(!a.aeq_sf(b, 4));
+        assert!(a.aeq_sf_mode(b, 4, SfMode::Distance(Reference::Max)));
+
+        let a = 99.945_1_f64;
+        let b = 99.954_9_f64;
+        assert!(a.aeq_sf(b, 4));
+        assert!(!a.aeq_sf_mode(b, 4, SfMode::Distance(Reference::Max)));
+
+        let a = 9.996_f64;
+        let b = 10.004_f64;
+        assert!(a.aeq_sf_mode(b, 3, SfMode::Distance(Reference::Second)));
+        assert!(!a.aeq_sf_mode(b, 3, SfMode::Distance(Reference::First)));
+
+        assert!(0.0_f64.aeq_sf_mode(0.0, 3, SfMode::Distance(Reference::Max)));
+        assert!(!0.0_f64.aeq_sf_mode(1e-300, 3, SfMode::Distance(Reference::Min)));
+    }
+
+    #[test]
+    fn aeq_sf_modes_count_the_same_figures() {
+        let distance = SfMode::Distance(Reference::Max);
+        for (a, b) in [
+            (0.001_234_f64, 0.001_233),
+            (-123.4, -123.2),
+            (-0.012_34, -0.012_32),
+        ] {
+            assert!(a.aeq_sf(b, 3));
+            assert!(a.aeq_sf_mode(b, 3, distance));
+            assert!(!a.aeq_sf(b, 4));
+            assert!(!a.aeq_sf_mode(b, 4, distance));
+        }
+
+        assert!(!0.001_234_f64.aeq_sf(0.009, 1));
+        assert!(!0.001_234_f64.aeq_sf_mode(0.009, 1, distance));
+    }
+
+    #[test]
+    fn aeq_sf_distance_mode_f32() {
+        let a = Some(99.944_9_f32);
+        let b = Some(99.945_1_f32);
+        assert!(!a.aeq_sf(b, 4));
+        assert!(a.aeq_sf_mode(b, 4, SfMode::Distance(Reference::Max)));
+
+        let a = Ok::<_, Error>(1.0_f32);
+        let b = Ok(1.1_f32);
+        assert!(!a.aeq_sf_mode(b, 2, SfMode::Distance(Reference::Mean)));
+    }
 }
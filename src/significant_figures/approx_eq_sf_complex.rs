@@ -0,0 +1,274 @@
+#![allow(clippy::float_cmp)]
+
+use num::complex::Complex;
+use num_traits::Float;
+
+use super::approx_eq_sf::ApproxEqSf;
+use super::round_sf::half_step;
+use crate::decimals::approx_eq_complex_dec::{both_finite, both_zero, phase_diff, ComplexMode};
+use crate::float_like::FloatLike;
+use crate::special_values::SpecialValuePolicy;
+
+pub trait ApproxEqSfComplex: Sized {
+    /// Whether both complex numbers agree to the specified number of significant figures under
+    /// `mode`, treating special values as `policy` specifies.
+    fn aeq_sf_complex_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: ComplexMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool;
+
+    /// Whether both complex numbers agree to the specified number of significant figures under
+    /// `mode`.
+    fn aeq_sf_complex(&self, other: Self, significant_figures: u8, mode: ComplexMode) -> bool {
+        self.aeq_sf_complex_with_policy(
+            other,
+            significant_figures,
+            mode,
+            &SpecialValuePolicy::default(),
+        )
+    }
+
+    /// Negation of `aeq_sf_complex_with_policy`.
+    fn nae_sf_complex_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: ComplexMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        !self.aeq_sf_complex_with_policy(other, significant_figures, mode, policy)
+    }
+
+    /// Negation of `aeq_sf_complex`.
+    fn nae_sf_complex(&self, other: Self, significant_figures: u8, mode: ComplexMode) -> bool {
+        !self.aeq_sf_complex(other, significant_figures, mode)
+    }
+}
+
+/// Half a unit in the last of `significant_figures` figures of a number in `[1, 10)`, the
+/// tolerance for relative differences and phases.
+fn relative_half_step<F: Float>(significant_figures: u8) -> F {
+    half_step(F::one(), significant_figures)
+}
+
+impl<F: FloatLike> ApproxEqSfComplex for Complex<F> {
+    /// `Componentwise` rounds each part to the significant figures, `Distance` compares `|a - b|`
+    /// with half a unit in the last figure of the larger modulus, `ModulusRelative` compares
+    /// `|a - b| / |a|` and `Polar` compares `||a| - |b|| / max(|a|, |b|)` and the phase difference
+    /// in radians, each with half a unit in the last figure of a number in `[1, 10)`.
+    ///
+    /// Non-finite components and two zeros are compared componentwise in every mode, so `policy`
+    /// decides them.
+    fn aeq_sf_complex_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: ComplexMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if !both_finite(*self, other) || both_zero(*self, other) {
+            return self.aeq_sf_with_policy(other, significant_figures, policy);
+        }
+
+        let distance = (*self - other).norm();
+        let aeq_sf_complex = match mode {
+            ComplexMode::Componentwise => {
+                self.aeq_sf_with_policy(other, significant_figures, policy)
+            }
+            ComplexMode::Distance => {
+                let modulus = self.norm().max(other.norm());
+
+                distance <= half_step(modulus, significant_figures)
+            }
+            ComplexMode::ModulusRelative => {
+                let modulus = self.norm();
+                if modulus.is_zero() {
+                    return false;
+                }
+
+                distance / modulus <= relative_half_step(significant_figures)
+            }
+            ComplexMode::Polar => {
+                let modulus = self.norm().max(other.norm());
+                let moduli_diff = (self.norm() - other.norm()).abs() / modulus;
+                let moduli_aeq_sf = moduli_diff <= relative_half_step(significant_figures);
+                let either_zero = self.norm().is_zero() || other.norm().is_zero();
+
+                moduli_aeq_sf
+                    && (either_zero
+                        || phase_diff(*self, other) <= relative_half_step(significant_figures))
+            }
+        };
+
+        aeq_sf_complex
+    }
+}
+
+impl<T: ApproxEqSfComplex> ApproxEqSfComplex for Option<T> {
+    fn aeq_sf_complex_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: ComplexMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if self.is_none() && other.is_none() {
+            return policy.none_equal;
+        }
+
+        if self.is_some() && other.is_some() {
+            let Some(first) = self else {
+                return false;
+            };
+            let Some(second) = other else {
+                return false;
+            };
+
+            let aeq_sf_complex =
+                first.aeq_sf_complex_with_policy(second, significant_figures, mode, policy);
+
+            return aeq_sf_complex;
+        }
+
+        false
+    }
+}
+
+impl<T: ApproxEqSfComplex, E> ApproxEqSfComplex for Result<T, E> {
+    fn aeq_sf_complex_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: ComplexMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if self.is_err() && other.is_err() {
+            return policy.err_equal;
+        }
+
+        if self.is_ok() && other.is_ok() {
+            let Ok(first) = self else {
+                return false;
+            };
+            let Ok(second) = other else {
+                return false;
+            };
+
+            let aeq_sf_complex =
+                first.aeq_sf_complex_with_policy(second, significant_figures, mode, policy);
+
+            return aeq_sf_complex;
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+    use num::complex::{Complex32, Complex64};
+
+    use super::ApproxEqSfComplex;
+    use crate::decimals::approx_eq_complex_dec::ComplexMode;
+    use crate::special_values::SpecialValuePolicy;
+    use crate::ApproxEqSf;
+
+    #[test]
+    fn near_zero_component() {
+        let a = Complex64::new(100.0, 0.004);
+        let b = Complex64::new(100.0, -0.004);
+        assert!(!a.aeq_sf(b, 4));
+        assert!(a.nae_sf_complex(b, 4, ComplexMode::Componentwise));
+        assert!(a.aeq_sf_complex(b, 4, ComplexMode::Distance));
+        assert!(a.aeq_sf_complex(b, 4, ComplexMode::ModulusRelative));
+        assert!(a.aeq_sf_complex(b, 4, ComplexMode::Polar));
+
+        let a = Complex64::new(1.0, 1e-17);
+        let b = Complex64::new(1.0, -2e-17);
+        assert!(a.aeq_sf_complex(b, 6, ComplexMode::Distance));
+        assert!(a.aeq_sf_complex(b, 6, ComplexMode::ModulusRelative));
+        assert!(a.aeq_sf_complex(b, 6, ComplexMode::Polar));
+
+        let a = Complex32::new(1.0, 1e-8);
+        let b = Complex32::new(1.0, -2e-8);
+        assert!(a.aeq_sf_complex(b, 6, ComplexMode::ModulusRelative));
+    }
+
+    #[test]
+    fn modulus_relative_and_polar() {
+        let a = Complex64::new(100.0, 0.0);
+        let b = Complex64::new(100.04, 0.0);
+        assert!(a.aeq_sf_complex(b, 4, ComplexMode::ModulusRelative));
+        assert!(a.nae_sf_complex(b, 5, ComplexMode::ModulusRelative));
+
+        // Same modulus, phases 0.01 rad apart.
+        let a = Complex64::from_polar(2.0, 0.5);
+        let b = Complex64::from_polar(2.0, 0.51);
+        assert!(a.aeq_sf_complex(b, 1, ComplexMode::Polar));
+        assert!(a.nae_sf_complex(b, 3, ComplexMode::Polar));
+
+        // Phases just either side of ±π are close.
+        let a = Complex64::new(-1.0, 1e-12);
+        let b = Complex64::new(-1.0, -1e-12);
+        assert!(a.aeq_sf_complex(b, 9, ComplexMode::Polar));
+
+        // Moduli are compared relative to the larger one, not rounded.
+        let a = Complex64::new(0.012_3, 0.0);
+        let b = Complex64::new(0.045_6, 0.0);
+        assert!(a.nae_sf_complex(b, 2, ComplexMode::Polar));
+        assert!(a.aeq_sf_complex(Complex64::new(0.012_31, 0.0), 3, ComplexMode::Polar));
+        assert!(a.nae_sf_complex(Complex64::new(0.012_4, 0.0), 3, ComplexMode::Polar));
+
+        let zero = Complex64::new(0.0, 0.0);
+        assert!(zero.aeq_sf_complex(zero, 3, ComplexMode::Distance));
+        assert!(zero.aeq_sf_complex(zero, 3, ComplexMode::Polar));
+        assert!(zero.nae_sf_complex(a, 3, ComplexMode::Polar));
+        assert!(zero.nae_sf_complex(a, 3, ComplexMode::ModulusRelative));
+
+        // Two zeros differing in sign are decided by the policy in every mode.
+        let negative_zero = Complex64::new(0.0, -0.0);
+        let policy = SpecialValuePolicy {
+            signed_zero_equal: false,
+            ..SpecialValuePolicy::default()
+        };
+        for mode in [
+            ComplexMode::Componentwise,
+            ComplexMode::Distance,
+            ComplexMode::ModulusRelative,
+            ComplexMode::Polar,
+        ] {
+            assert!(zero.aeq_sf_complex(negative_zero, 3, mode));
+            assert!(zero.nae_sf_complex_with_policy(negative_zero, 3, mode, &policy));
+        }
+    }
+
+    #[test]
+    fn special_values() {
+        let a = Complex64::new(f64::INFINITY, 0.0);
+        assert!(a.aeq_sf_complex(a, 3, ComplexMode::Polar));
+
+        let a = Complex64::new(f64::NAN, 0.0);
+        assert!(a.nae_sf_complex(a, 3, ComplexMode::ModulusRelative));
+    }
+
+    #[test]
+    fn option_result() {
+        let a = Some(Complex64::new(1.0, 1e-17));
+        let b = Some(Complex64::new(1.0, -2e-17));
+        assert!(a.aeq_sf_complex(b, 6, ComplexMode::Polar));
+        assert!(a.nae_sf_complex(None, 6, ComplexMode::Polar));
+        assert!(None::<Complex64>.aeq_sf_complex(None, 6, ComplexMode::Polar));
+
+        let a = Ok::<Complex32, Error>(Complex32::new(1.0, 1e-8));
+        let b = Ok::<Complex32, Error>(Complex32::new(1.0, -2e-8));
+        assert!(a.aeq_sf_complex(b, 6, ComplexMode::ModulusRelative));
+
+        let a = Ok::<Complex32, Error>(Complex32::new(1.0, 1e-8));
+        let b = Err(Error::msg(""));
+        assert!(a.nae_sf_complex(b, 6, ComplexMode::ModulusRelative));
+    }
+}
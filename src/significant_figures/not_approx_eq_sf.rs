@@ -1,242 +1,57 @@
-#![allow(clippy::float_cmp)]
-
-#[cfg(feature = "num")]
-use num::complex::{Complex32, Complex64};
-
-use super::round_sf::RoundToSigDig;
-
-pub trait NApproxEqSf {
-    fn nae_sf(&self, other: Self, significant_figures: u8) -> bool;
-}
-
-impl NApproxEqSf for f64 {
-    fn nae_sf(&self, other: Self, significant_figures: u8) -> bool {
-        let first = self.round_to_sf(significant_figures);
-        let second = other.round_to_sf(significant_figures);
-
-        let nae_sf = first != second;
-
-        nae_sf
-    }
-}
-
-impl NApproxEqSf for Option<f64> {
-    fn nae_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_none() && other.is_none() {
-            return false;
-        }
-
-        if self.is_some() && other.is_some() {
-            let Some(first) = self else {
-                return true;
-            };
-            let Some(second) = other else {
-                return true;
-            };
-
-            let first = first.round_to_sf(significant_figures);
-            let second = second.round_to_sf(significant_figures);
-            let nae_sf = first != second;
-
-            return nae_sf;
-        }
-
-        true
-    }
-}
-
-impl<E> NApproxEqSf for Result<f64, E> {
-    fn nae_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_err() && other.is_err() {
-            return false;
-        }
-
-        if self.is_ok() && other.is_ok() {
-            let Ok(first) = self else {
-                return true;
-            };
-            let Ok(second) = other else {
-                return true;
-            };
-
-            let first = first.round_to_sf(significant_figures);
-            let second = second.round_to_sf(significant_figures);
-            let nae_sf = first != second;
-
-            return nae_sf;
-        }
-
-        true
-    }
-}
-
-#[cfg(feature = "num")]
-impl NApproxEqSf for Complex64 {
-    fn nae_sf(&self, other: Self, significant_figures: u8) -> bool {
-        let real_nae_sf = self.re.nae_sf(other.re, significant_figures);
-        let imaginary_nae_sf = self.im.nae_sf(other.im, significant_figures);
-        let nae_sf = real_nae_sf || imaginary_nae_sf;
-
-        nae_sf
-    }
-}
-
-#[cfg(feature = "num")]
-impl NApproxEqSf for Option<Complex64> {
-    fn nae_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_some() && other.is_some() {
-            let first = unsafe { self.unwrap_unchecked() };
-            let second = unsafe { other.unwrap_unchecked() };
-            let real_nae_sf = first.re.nae_sf(second.re, significant_figures);
-            let imaginary_nae_sf = first.im.nae_sf(second.im, significant_figures);
-            let nae_sf = real_nae_sf || imaginary_nae_sf;
-
-            return nae_sf;
-        }
-
-        if self.is_none() && other.is_none() {
-            return false;
-        }
-
-        true
-    }
-}
-
-#[cfg(feature = "num")]
-impl<E> NApproxEqSf for Result<Complex64, E> {
-    fn nae_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_ok() && other.is_ok() {
-            let first = unsafe { self.as_ref().unwrap_unchecked() };
-            let second = unsafe { other.as_ref().unwrap_unchecked() };
-            let real_nae_sf = first.re.nae_sf(second.re, significant_figures);
-            let imaginary_nae_sf = first.im.nae_sf(second.im, significant_figures);
-            let nae_sf = real_nae_sf || imaginary_nae_sf;
-
-            return nae_sf;
-        }
-
-        if self.is_err() && other.is_err() {
-            return false;
-        }
-
-        true
-    }
-}
-
-impl NApproxEqSf for f32 {
-    fn nae_sf(&self, other: Self, significant_figures: u8) -> bool {
-        let first = self.round_to_sf(significant_figures);
-        let second = other.round_to_sf(significant_figures);
-
-        let aeq = first != second;
-
-        aeq
-    }
-}
-
-impl NApproxEqSf for Option<f32> {
+use super::approx_eq_sf::{ApproxEqSf, SfMode};
+use crate::special_values::SpecialValuePolicy;
+
+/// Negation of `ApproxEqSf`, implemented for every type that implements it.
+pub trait NApproxEqSf: Sized {
+    /// Negation of `ApproxEqSf::aeq_sf_mode_with_policy`.
+    fn nae_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool;
+
+    /// Negation of `ApproxEqSf::aeq_sf_mode`.
+    fn nae_sf_mode(&self, other: Self, significant_figures: u8, mode: SfMode) -> bool {
+        self.nae_sf_mode_with_policy(
+            other,
+            significant_figures,
+            mode,
+            &SpecialValuePolicy::default(),
+        )
+    }
+
+    /// Negation of `ApproxEqSf::aeq_sf_with_policy`.
+    fn nae_sf_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        self.nae_sf_mode_with_policy(other, significant_figures, SfMode::Rounded, policy)
+    }
+
+    /// Negation of `ApproxEqSf::aeq_sf`.
     fn nae_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_none() && other.is_none() {
-            return false;
-        }
-
-        if self.is_some() && other.is_some() {
-            let Some(first) = self else {
-                return true;
-            };
-            let Some(second) = other else {
-                return true;
-            };
-
-            let first = first.round_to_sf(significant_figures);
-            let second = second.round_to_sf(significant_figures);
-            let nae_sf = first != second;
-
-            return nae_sf;
-        }
-
-        true
+        self.nae_sf_with_policy(other, significant_figures, &SpecialValuePolicy::default())
     }
 }
 
-impl<E> NApproxEqSf for Result<f32, E> {
-    fn nae_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_err() && other.is_err() {
-            return false;
-        }
-
-        if self.is_ok() && other.is_ok() {
-            let Ok(first) = self else {
-                return true;
-            };
-            let Ok(second) = other else {
-                return true;
-            };
-
-            let first = first.round_to_sf(significant_figures);
-            let second = second.round_to_sf(significant_figures);
-            let nae_sf = first != second;
-
-            return nae_sf;
-        }
-
-        true
-    }
-}
-
-#[cfg(feature = "num")]
-impl NApproxEqSf for Complex32 {
-    fn nae_sf(&self, other: Self, significant_figures: u8) -> bool {
-        let real_nae_sf = self.re.nae_sf(other.re, significant_figures);
-        let imaginary_nae_sf = self.im.nae_sf(other.im, significant_figures);
-        let nae_sf = real_nae_sf || imaginary_nae_sf;
+impl<T: ApproxEqSf> NApproxEqSf for T {
+    fn nae_sf_mode_with_policy(
+        &self,
+        other: Self,
+        significant_figures: u8,
+        mode: SfMode,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let nae_sf = !self.aeq_sf_mode_with_policy(other, significant_figures, mode, policy);
 
         nae_sf
     }
 }
 
-#[cfg(feature = "num")]
-impl NApproxEqSf for Option<Complex32> {
-    fn nae_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_some() && other.is_some() {
-            let first = unsafe { self.unwrap_unchecked() };
-            let second = unsafe { other.unwrap_unchecked() };
-            let real_nae_sf = first.re.nae_sf(second.re, significant_figures);
-            let imaginary_nae_sf = first.im.nae_sf(second.im, significant_figures);
-            let nae_sf = real_nae_sf || imaginary_nae_sf;
-
-            return nae_sf;
-        }
-
-        if self.is_none() && other.is_none() {
-            return false;
-        }
-
-        true
-    }
-}
-
-#[cfg(feature = "num")]
-impl<E> NApproxEqSf for Result<Complex32, E> {
-    fn nae_sf(&self, other: Self, significant_figures: u8) -> bool {
-        if self.is_ok() && other.is_ok() {
-            let first = unsafe { self.as_ref().unwrap_unchecked() };
-            let second = unsafe { other.as_ref().unwrap_unchecked() };
-            let real_nae_sf = first.re.nae_sf(second.re, significant_figures);
-            let imaginary_nae_sf = first.im.nae_sf(second.im, significant_figures);
-            let nae_sf = real_nae_sf || imaginary_nae_sf;
-
-            return nae_sf;
-        }
-
-        if self.is_err() && other.is_err() {
-            return false;
-        }
-
-        true
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use anyhow::Error;
@@ -244,6 +59,8 @@ mod tests {
     #[cfg(feature = "num")]
     use num::complex::Complex64;
 
+    use crate::relative::approx_eq_rel::Reference;
+    use crate::significant_figures::approx_eq_sf::SfMode;
     use crate::significant_figures::not_approx_eq_sf::NApproxEqSf;
 
     #[test]
@@ -403,4 +220,16 @@ mod tests {
         let b = Err(Error::msg("message"));
         assert!(!a.nae_sf(b, 4));
     }
+
+    #[test]
+    fn nae_sf_distance_mode() {
+        let a = 99.944_999_f64;
+        let b = 99.945_001_f64;
+        assert!(a.nae_sf(b, 4));
+        assert!(!a.nae_sf_mode(b, 4, SfMode::Distance(Reference::Max)));
+
+        let a = Some(99.945_1_f64);
+        let b = Some(99.954_9_f64);
+        assert!(a.nae_sf_mode(b, 4, SfMode::Distance(Reference::Max)));
+    }
 }
@@ -1,56 +1,73 @@
-use core::fmt::Debug;
+use num_traits::Float;
 
-use cast::f32;
-use cast::f64;
-use num_traits::{Float, Zero};
 pub trait RoundToSigDig {
     /// Round `float_number` to specified number of significant figures.
-    fn round_to_sf(&self, significant_figures: u8) -> Self;
-}
-
-impl RoundToSigDig for f64 {
-    /// Round `float_number` to specified number of significant figures.
+    ///
+    /// Digits are counted from the leading digit of `|float_number|`, so the sign is not a digit
+    /// and the zeros after the decimal point of a value below one are not significant. Values so
+    /// small that the scaled value is not finite are returned unchanged.
     fn round_to_sf(&self, significant_figures: u8) -> Self
     where
-        Self: Float + Debug,
+        Self: Float,
     {
-        if self.is_zero() || significant_figures.is_zero() {
-            return 0.0;
+        if self.is_zero() || significant_figures == 0 {
+            return Self::zero();
         }
 
-        let whole_digits = f64(self.round().to_string().len());
-        let digit_shift = f64(significant_figures) - whole_digits;
-        let shifted_num = self * 10.0.powf(digit_shift);
-        let shifted_rounded = shifted_num.round();
-        let rounded = shifted_rounded / 10.0.powf(digit_shift);
+        if !self.is_finite() {
+            return *self;
+        }
+
+        let ten = Self::from(10.0).unwrap();
+        let digit_shift = i32::from(significant_figures) - 1 - exponent(*self);
+        let rounded = if digit_shift < 0 {
+            let step = ten.powi(-digit_shift);
+            (*self / step).round() * step
+        } else {
+            let factor = ten.powi(digit_shift);
+            (*self * factor).round() / factor
+        };
+
+        if !rounded.is_finite() {
+            return *self;
+        }
 
         rounded
     }
 }
 
-impl RoundToSigDig for f32 {
-    /// Round `float_number` to specified number of significant figures.
-    fn round_to_sf(&self, significant_figures: u8) -> Self
-    where
-        Self: Float + Debug,
-    {
-        if self.is_zero() || significant_figures.is_zero() {
-            return 0.0;
-        }
+impl<F> RoundToSigDig for F where F: Float {}
+
+/// Decimal exponent of the leading significant digit, i.e. `floor(log10(|x|))`.
+///
+/// `log10` can be off by one right next to a power of ten, so the estimate is corrected
+/// against `10^exponent` before it is returned.
+pub(crate) fn exponent<F: Float>(x: F) -> i32 {
+    let ten = F::from(10.0).unwrap();
+    let abs = x.abs();
+    let mut exponent = abs.log10().floor().to_i32().unwrap_or(0);
+
+    if ten.powi(exponent) > abs {
+        exponent -= 1;
+    } else if ten.powi(exponent + 1) <= abs {
+        exponent += 1;
+    }
 
-        let whole_digits = f32(self.round().to_string().len());
-        let digit_shift = f32(significant_figures) - whole_digits;
-        let shifted_num = self * 10.0.powf(digit_shift);
-        let shifted_rounded = shifted_num.round();
-        let rounded = shifted_rounded / 10.0.powf(digit_shift);
+    exponent
+}
 
-        rounded
-    }
+/// Half of one unit in the last significant figure of `x`, i.e. `0.5 * 10^(exponent - sf + 1)`.
+pub(crate) fn half_step<F: Float>(x: F, significant_figures: u8) -> F {
+    let ten = F::from(10.0).unwrap();
+    let half = F::from(0.5).unwrap();
+    let step_exponent = exponent(x) - i32::from(significant_figures) + 1;
+
+    half * ten.powi(step_exponent)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::RoundToSigDig;
+    use super::{exponent, half_step, RoundToSigDig};
 
     #[test]
     #[allow(clippy::float_cmp)]
@@ -91,4 +108,32 @@ mod tests {
         let after = before.round_to_sf(2);
         assert_eq!(after, 120.0);
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn round_to_sf_negative_and_below_one() {
+        assert_eq!((-123.4_f64).round_to_sf(3), -123.0);
+        assert_eq!((-0.5_f64).round_to_sf(1), -0.5);
+        assert_eq!(0.001_234_f64.round_to_sf(3), 0.001_23);
+        assert_eq!(0.012_f64.round_to_sf(2), 0.012);
+        assert_eq!((-0.001_299_f64).round_to_sf(2), -0.001_3);
+        assert_eq!(0.099_96_f32.round_to_sf(3), 0.1);
+        assert_eq!(1e-310_f64.round_to_sf(17), 1e-310);
+        assert_eq!(f64::MAX.round_to_sf(1), f64::MAX);
+    }
+
+    #[test]
+    fn exponent_of_leading_digit() {
+        assert_eq!(exponent(123.4_f64), 2);
+        assert_eq!(exponent(-0.012_3_f64), -2);
+        assert_eq!(exponent(1000.0_f64), 3);
+        assert_eq!(exponent(999.999_f32), 2);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn half_step_of_last_figure() {
+        assert_eq!(half_step(123.4_f64, 3), 0.5);
+        assert_eq!(half_step(123.4_f64, 1), 50.0);
+    }
 }
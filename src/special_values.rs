@@ -0,0 +1,253 @@
+use num_traits::Float;
+
+/// How comparisons treat NaN, infinities and signed zeros.
+///
+/// The default follows IEEE equality for NaN, but treats matching infinities as equal and
+/// `-0.0` as identical to `0.0`. The policy also decides whether two `None`s or two `Err`s are
+/// equal, which they are by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct SpecialValuePolicy {
+    /// Whether NaN compares equal to NaN.
+    pub nan_equal: bool,
+    /// Whether an infinity compares equal to an infinity of the same sign.
+    pub inf_equal: bool,
+    /// Whether `-0.0` and `0.0` count as identical.
+    pub signed_zero_equal: bool,
+    /// Whether `None` compares equal to `None`.
+    pub none_equal: bool,
+    /// Whether `Err` compares equal to `Err`, whatever the errors.
+    pub err_equal: bool,
+}
+
+impl Default for SpecialValuePolicy {
+    fn default() -> Self {
+        Self {
+            nan_equal: false,
+            inf_equal: true,
+            signed_zero_equal: true,
+            none_equal: true,
+            err_equal: true,
+        }
+    }
+}
+
+impl SpecialValuePolicy {
+    /// Default policy except that two `None`s or two `Err`s are never equal, so a comparison
+    /// expecting values fails when both sides are missing.
+    pub fn strict() -> Self {
+        Self {
+            none_equal: false,
+            err_equal: false,
+            ..Self::default()
+        }
+    }
+
+    /// Outcome of comparing `first` and `second` if either is a special value, otherwise `None`.
+    ///
+    /// Finite values are never equal to NaN or an infinity, whatever the tolerance.
+    pub fn resolve<F: Float>(&self, first: F, second: F) -> Option<bool> {
+        if first.is_nan() || second.is_nan() {
+            let equal = self.nan_equal && first.is_nan() && second.is_nan();
+            return Some(equal);
+        }
+
+        if first.is_infinite() || second.is_infinite() {
+            let equal = self.inf_equal && first == second;
+            return Some(equal);
+        }
+
+        if first.is_zero() && second.is_zero() {
+            let same_sign = first.is_sign_negative() == second.is_sign_negative();
+            let equal = self.signed_zero_equal || same_sign;
+            return Some(equal);
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpecialValuePolicy;
+    use crate::decimals::round_dec::RoundingMode;
+    use crate::relative::approx_eq_rel::Reference;
+    use crate::relative::is_close::IsCloseTolerance;
+    use crate::significant_figures::not_approx_eq_sf::NApproxEqSf;
+    use crate::tolerance::Tolerance;
+    use crate::{
+        ApproxEq, ApproxEqRel, ApproxEqRounded, ApproxEqSf, ApproxEqUlps, ApproxEqWith, IsClose,
+        NApproxEq,
+    };
+
+    const VALUES: [f64; 14] = [
+        0.0,
+        -0.0,
+        1e-300,
+        -1e-300,
+        0.1,
+        0.15,
+        99.949_999,
+        99.950_001,
+        1e20,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        -f64::NAN,
+    ];
+
+    fn policies() -> Vec<SpecialValuePolicy> {
+        let mut policies = Vec::new();
+        for nan_equal in [false, true] {
+            for inf_equal in [false, true] {
+                for signed_zero_equal in [false, true] {
+                    policies.push(SpecialValuePolicy {
+                        nan_equal,
+                        inf_equal,
+                        signed_zero_equal,
+                        ..SpecialValuePolicy::default()
+                    });
+                }
+            }
+        }
+
+        policies
+    }
+
+    #[test]
+    fn default_policy() {
+        assert!(f64::INFINITY.aeq(f64::INFINITY, 3));
+        assert!(!f64::INFINITY.aeq(f64::NEG_INFINITY, 3));
+        assert!(!f64::INFINITY.aeq(f64::MAX, 3));
+        assert!(!f64::NAN.aeq(f64::NAN, 3));
+        assert!(0.0_f64.aeq(-0.0, 3));
+
+        assert!(f32::INFINITY.aeq_sf(f32::INFINITY, 3));
+        assert!(!f32::NAN.aeq_sf(f32::NAN, 3));
+        assert!(f64::INFINITY.aeq_rel(f64::INFINITY, 1e-9));
+        assert!(!f64::NAN.aeq_ulps(f64::NAN, u32::MAX));
+    }
+
+    #[test]
+    fn configured_policy() {
+        let policy = SpecialValuePolicy {
+            nan_equal: true,
+            inf_equal: false,
+            signed_zero_equal: false,
+            ..SpecialValuePolicy::default()
+        };
+
+        assert!(f64::NAN.aeq_with_policy(f64::NAN, 3, &policy));
+        assert!(!f64::NAN.aeq_with_policy(1.0, 3, &policy));
+        assert!(!f64::INFINITY.aeq_with_policy(f64::INFINITY, 3, &policy));
+        assert!(!0.0_f64.aeq_with_policy(-0.0, 3, &policy));
+        assert!(0.0_f64.aeq_with_policy(1e-300, 3, &policy));
+
+        assert!(Some(f32::NAN).aeq_sf_with_policy(Some(f32::NAN), 3, &policy));
+        assert!(!0.0_f64.aeq_ulps_with_policy(-0.0, 1, &policy));
+        assert!(f64::NAN.approx_eq_with_policy(&f64::NAN, &Tolerance::Abs(0.0), &policy));
+    }
+
+    #[test]
+    fn strict_policy() {
+        let strict = SpecialValuePolicy::strict();
+
+        assert!(None::<f64>.aeq(None, 3));
+        assert!(!None::<f64>.aeq_with_policy(None, 3, &strict));
+        assert!(Some(1.0_f64).aeq_with_policy(Some(1.000_1), 3, &strict));
+        assert!(!Err::<f32, ()>(()).aeq_sf_with_policy(Err(()), 3, &strict));
+        assert!(!None::<f64>.approx_eq_with_policy(&None, &Tolerance::Abs(1.0), &strict));
+        assert!(!Err::<f64, ()>(()).aeq_ulps_with_policy(Err(()), 4, &strict));
+
+        let is_close = IsCloseTolerance {
+            special_values: strict,
+            ..Default::default()
+        };
+        assert!(!None::<f64>.is_close(None, &is_close));
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn nae_is_negation_of_aeq_for_all_inputs() {
+        for policy in policies() {
+            for first in VALUES {
+                for second in VALUES {
+                    for precision in [0, 1, 3, 4, 17] {
+                        assert_eq!(
+                            first.nae_with_policy(second, precision, &policy),
+                            !first.aeq_with_policy(second, precision, &policy)
+                        );
+                        assert_eq!(
+                            first.nae_sf_with_policy(second, precision, &policy),
+                            !first.aeq_sf_with_policy(second, precision, &policy)
+                        );
+                        assert_eq!(
+                            first.nae_rounded_mode_with_policy(
+                                second,
+                                precision,
+                                RoundingMode::HalfEven,
+                                &policy
+                            ),
+                            !first.aeq_rounded_mode_with_policy(
+                                second,
+                                precision,
+                                RoundingMode::HalfEven,
+                                &policy
+                            )
+                        );
+                        assert_eq!(
+                            first.nae_ulps_with_policy(second, u32::from(precision), &policy),
+                            !first.aeq_ulps_with_policy(second, u32::from(precision), &policy)
+                        );
+
+                        let first = first as f32;
+                        let second = second as f32;
+                        assert_eq!(
+                            first.nae_with_policy(second, precision, &policy),
+                            !first.aeq_with_policy(second, precision, &policy)
+                        );
+                        assert_eq!(
+                            first.nae_sf_with_policy(second, precision, &policy),
+                            !first.aeq_sf_with_policy(second, precision, &policy)
+                        );
+                    }
+
+                    let rel_tol = 1e-6;
+                    let reference = Reference::Max;
+                    assert_eq!(
+                        first.nae_rel_to_with_policy(second, rel_tol, reference, &policy),
+                        !first.aeq_rel_to_with_policy(second, rel_tol, reference, &policy)
+                    );
+
+                    let tolerance =
+                        Tolerance::Any(vec![Tolerance::Abs(0.1), Tolerance::SigFigs(3)]);
+                    assert_eq!(
+                        first.approx_ne_with_policy(&second, &tolerance, &policy),
+                        !first.approx_eq_with_policy(&second, &tolerance, &policy)
+                    );
+
+                    let is_close = IsCloseTolerance {
+                        special_values: policy,
+                        ..Default::default()
+                    };
+                    assert_eq!(
+                        first.not_close(second, &is_close),
+                        !first.is_close(second, &is_close)
+                    );
+
+                    for wrapped in [None, Some(second)] {
+                        assert_eq!(
+                            Some(first).nae_with_policy(wrapped, 2, &policy),
+                            !Some(first).aeq_with_policy(wrapped, 2, &policy)
+                        );
+                        assert_eq!(
+                            Some(first).nae_sf_with_policy(wrapped, 2, &policy),
+                            !Some(first).aeq_sf_with_policy(wrapped, 2, &policy)
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
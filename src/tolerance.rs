@@ -0,0 +1,195 @@
+pub mod approx_eq_result;
+pub mod approx_eq_with;
+
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+/// Tolerance for an approximate comparison, as data rather than a choice of trait.
+///
+/// Parses from and displays as `decimals:3`, `sf:4`, `abs:1e-9`, `rel:1e-6`, `ulps:4`,
+/// `any(rel:1e-9, abs:1e-12)` and `all(..)`.
+///
+/// `Abs` and `Rel` hold an `f64` so that one tolerance serves every float type; the values are
+/// not widened, the tolerance is converted to their type, e.g. rounded to the nearest `f32`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Tolerance {
+    /// Absolute difference below `10^-decimals`, as `ApproxEq::aeq`.
+    Decimals(u8),
+    /// Same value at this many significant figures, as `ApproxEqSf::aeq_sf`.
+    SigFigs(u8),
+    /// Absolute difference of at most this value.
+    Abs(f64),
+    /// Difference of at most this fraction of the larger magnitude, as `ApproxEqRel::aeq_rel`.
+    Rel(f64),
+    /// At most this many representable values apart, as `ApproxEqUlps::aeq_ulps`.
+    Ulps(u32),
+    /// At least one of the tolerances is met; never for an empty list.
+    Any(Vec<Tolerance>),
+    /// Every one of the tolerances is met; always for an empty list.
+    All(Vec<Tolerance>),
+}
+
+/// Error returned when a string is not a valid `Tolerance`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseToleranceError {
+    input: String,
+}
+
+impl Display for ParseToleranceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid tolerance: `{}`", self.input)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for ParseToleranceError {}
+
+impl FromStr for Tolerance {
+    type Err = ParseToleranceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseToleranceError {
+            input: s.to_owned(),
+        };
+        let s = s.trim();
+
+        let combination = s
+            .strip_suffix(')')
+            .and_then(|s| s.split_once('('))
+            .map(|(name, inner)| (name.trim(), inner));
+        if let Some((name, inner)) = combination {
+            let tolerances = split_top_level(inner)
+                .into_iter()
+                .filter(|part| !part.trim().is_empty())
+                .map(str::parse)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| error())?;
+
+            let tolerance = match name {
+                "any" => Tolerance::Any(tolerances),
+                "all" => Tolerance::All(tolerances),
+                _ => return Err(error()),
+            };
+
+            return Ok(tolerance);
+        }
+
+        let (name, value) = s.split_once(':').ok_or_else(error)?;
+        let value = value.trim();
+
+        let tolerance = match name.trim() {
+            "decimals" => Tolerance::Decimals(value.parse().map_err(|_| error())?),
+            "sf" => Tolerance::SigFigs(value.parse().map_err(|_| error())?),
+            "abs" => Tolerance::Abs(value.parse().map_err(|_| error())?),
+            "rel" => Tolerance::Rel(value.parse().map_err(|_| error())?),
+            "ulps" => Tolerance::Ulps(value.parse().map_err(|_| error())?),
+            _ => return Err(error()),
+        };
+
+        Ok(tolerance)
+    }
+}
+
+impl Display for Tolerance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, tolerances) = match self {
+            Tolerance::Decimals(decimals) => return write!(f, "decimals:{decimals}"),
+            Tolerance::SigFigs(significant_figures) => {
+                return write!(f, "sf:{significant_figures}")
+            }
+            Tolerance::Abs(abs_tol) => return write!(f, "abs:{abs_tol:e}"),
+            Tolerance::Rel(rel_tol) => return write!(f, "rel:{rel_tol:e}"),
+            Tolerance::Ulps(max_ulps) => return write!(f, "ulps:{max_ulps}"),
+            Tolerance::Any(tolerances) => ("any", tolerances),
+            Tolerance::All(tolerances) => ("all", tolerances),
+        };
+
+        write!(f, "{name}(")?;
+        for (index, tolerance) in tolerances.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{tolerance}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Splits on the commas that are not nested inside parentheses.
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+
+    for (index, character) in s.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&s[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Tolerance;
+    use crate::ApproxEqWith;
+
+    #[test]
+    fn parse_tolerance() {
+        assert_eq!("decimals:3".parse(), Ok(Tolerance::Decimals(3)));
+        assert_eq!(" sf: 4 ".parse(), Ok(Tolerance::SigFigs(4)));
+        assert_eq!("abs:1e-9".parse(), Ok(Tolerance::Abs(1e-9)));
+        assert_eq!("ulps:4".parse(), Ok(Tolerance::Ulps(4)));
+        assert_eq!(
+            "any(rel:1e-6, all(abs:0.5, ulps:2))".parse(),
+            Ok(Tolerance::Any(vec![
+                Tolerance::Rel(1e-6),
+                Tolerance::All(vec![Tolerance::Abs(0.5), Tolerance::Ulps(2)]),
+            ]))
+        );
+
+        assert!("decimals:-1".parse::<Tolerance>().is_err());
+        assert!("bits:3".parse::<Tolerance>().is_err());
+        assert!("some(sf:3)".parse::<Tolerance>().is_err());
+        assert!("sf".parse::<Tolerance>().is_err());
+    }
+
+    #[test]
+    fn empty_combinations() {
+        assert!(!1.0_f64.approx_eq(&1.0, &Tolerance::Any(vec![])));
+        assert!(1.0_f64.approx_eq(&2.0, &Tolerance::All(vec![])));
+        assert_eq!("any()".parse(), Ok(Tolerance::Any(vec![])));
+        assert_eq!(Tolerance::All(vec![]).to_string(), "all()");
+    }
+
+    #[test]
+    fn abs_and_rel_in_the_compared_type() {
+        assert!(0.5_f32.approx_eq(&0.75, &Tolerance::Abs(0.25)));
+        assert!(!0.5_f32.approx_eq(&0.75, &Tolerance::Abs(0.249)));
+        assert!(100.0_f32.approx_eq(&101.0, &Tolerance::Rel(0.01)));
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let tolerance = Tolerance::Any(vec![
+            Tolerance::Rel(1e-6),
+            Tolerance::All(vec![Tolerance::Decimals(3), Tolerance::SigFigs(2)]),
+        ]);
+        assert_eq!(
+            tolerance.to_string(),
+            "any(rel:1e-6, all(decimals:3, sf:2))"
+        );
+        assert_eq!(tolerance.to_string().parse(), Ok(tolerance));
+    }
+}
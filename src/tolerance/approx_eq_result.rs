@@ -0,0 +1,104 @@
+use super::approx_eq_with::ApproxEqWith;
+use super::Tolerance;
+use crate::special_values::SpecialValuePolicy;
+
+/// Comparison of `Result`s that looks at the errors instead of treating any two as equal.
+pub trait ApproxEqResult {
+    /// Error type of the result.
+    type Error;
+
+    /// Whether both are `Ok` and equal within `tolerance`, or both are `Err` and `err_eq` holds,
+    /// treating special values as `policy` specifies.
+    fn approx_eq_by_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        err_eq: impl FnOnce(&Self::Error, &Self::Error) -> bool,
+        policy: &SpecialValuePolicy,
+    ) -> bool;
+
+    /// Whether both are `Ok` and equal within `tolerance`, or both are `Err` and `err_eq` holds.
+    fn approx_eq_by(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        err_eq: impl FnOnce(&Self::Error, &Self::Error) -> bool,
+    ) -> bool {
+        self.approx_eq_by_with_policy(other, tolerance, err_eq, &SpecialValuePolicy::default())
+    }
+
+    /// Whether both are `Ok` and equal within `tolerance`, or both are `Err` with equal errors.
+    fn approx_eq_err_eq(&self, other: &Self, tolerance: &Tolerance) -> bool
+    where
+        Self::Error: PartialEq,
+    {
+        self.approx_eq_by(other, tolerance, |first, second| first == second)
+    }
+}
+
+impl<T: ApproxEqWith, E> ApproxEqResult for Result<T, E> {
+    type Error = E;
+
+    fn approx_eq_by_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        err_eq: impl FnOnce(&E, &E) -> bool,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if self.is_err() && other.is_err() {
+            let Err(first) = self else {
+                return false;
+            };
+            let Err(second) = other else {
+                return false;
+            };
+
+            let err_eq = err_eq(first, second);
+
+            return err_eq;
+        }
+
+        self.approx_eq_with_policy(other, tolerance, policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApproxEqResult;
+    use crate::tolerance::Tolerance;
+    use crate::ApproxEqWith;
+
+    #[derive(Debug, PartialEq)]
+    enum SolverError {
+        Diverged,
+        MaxIterations(u32),
+    }
+
+    #[test]
+    fn approx_eq_err_eq() {
+        let tolerance = Tolerance::Decimals(3);
+        let diverged = Err::<f64, _>(SolverError::Diverged);
+        let stopped = Err::<f64, _>(SolverError::MaxIterations(100));
+
+        assert!(diverged.approx_eq(&stopped, &tolerance));
+        assert!(!diverged.approx_eq_err_eq(&stopped, &tolerance));
+        assert!(diverged.approx_eq_err_eq(&Err(SolverError::Diverged), &tolerance));
+
+        assert!(Ok::<f64, SolverError>(1.0).approx_eq_err_eq(&Ok(1.000_1), &tolerance));
+        assert!(!Ok(1.0).approx_eq_err_eq(&diverged, &tolerance));
+    }
+
+    #[test]
+    fn approx_eq_by_closure() {
+        let tolerance = Tolerance::Abs(1e-9);
+        let first = Err::<f64, _>(SolverError::MaxIterations(100));
+        let second = Err::<f64, _>(SolverError::MaxIterations(200));
+
+        let same_kind = |first: &SolverError, second: &SolverError| {
+            core::mem::discriminant(first) == core::mem::discriminant(second)
+        };
+        assert!(first.approx_eq_by(&second, &tolerance, same_kind));
+        assert!(!first.approx_eq_by(&Err(SolverError::Diverged), &tolerance, same_kind));
+    }
+}
@@ -0,0 +1,239 @@
+#[cfg(feature = "num")]
+use num::complex::Complex;
+
+use super::Tolerance;
+use crate::float_like::FloatLike;
+use crate::special_values::SpecialValuePolicy;
+use crate::{ApproxEq, ApproxEqRel, ApproxEqSf, ApproxEqUlps};
+
+pub trait ApproxEqWith {
+    /// Whether `self` and `other` are equal within the specified tolerance, treating special
+    /// values as `policy` specifies.
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool;
+
+    /// Whether `self` and `other` are equal within the specified tolerance.
+    fn approx_eq(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        self.approx_eq_with_policy(other, tolerance, &SpecialValuePolicy::default())
+    }
+
+    /// Negation of `approx_eq_with_policy`.
+    fn approx_ne_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        !self.approx_eq_with_policy(other, tolerance, policy)
+    }
+
+    /// Negation of `approx_eq`.
+    fn approx_ne(&self, other: &Self, tolerance: &Tolerance) -> bool {
+        !self.approx_eq(other, tolerance)
+    }
+}
+
+impl<F: FloatLike> ApproxEqWith for F {
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if let Some(approx_eq) = policy.resolve(*self, *other) {
+            return approx_eq;
+        }
+
+        let approx_eq = match tolerance {
+            Tolerance::Decimals(decimals) => self.aeq(*other, *decimals),
+            Tolerance::SigFigs(significant_figures) => self.aeq_sf(*other, *significant_figures),
+            Tolerance::Abs(abs_tol) => (*self - *other).abs() <= F::from(*abs_tol).unwrap(),
+            Tolerance::Rel(rel_tol) => self.aeq_rel(*other, *rel_tol),
+            Tolerance::Ulps(max_ulps) => self.aeq_ulps(*other, *max_ulps),
+            Tolerance::Any(tolerances) => tolerances
+                .iter()
+                .any(|tolerance| self.approx_eq_with_policy(other, tolerance, policy)),
+            Tolerance::All(tolerances) => tolerances
+                .iter()
+                .all(|tolerance| self.approx_eq_with_policy(other, tolerance, policy)),
+        };
+
+        approx_eq
+    }
+}
+
+impl<T: ApproxEqWith> ApproxEqWith for Option<T> {
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if self.is_none() && other.is_none() {
+            return policy.none_equal;
+        }
+
+        if self.is_some() && other.is_some() {
+            let Some(first) = self else {
+                return false;
+            };
+            let Some(second) = other else {
+                return false;
+            };
+
+            let approx_eq = first.approx_eq_with_policy(second, tolerance, policy);
+
+            return approx_eq;
+        }
+
+        false
+    }
+}
+
+impl<T: ApproxEqWith, E> ApproxEqWith for Result<T, E> {
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if self.is_err() && other.is_err() {
+            return policy.err_equal;
+        }
+
+        if self.is_ok() && other.is_ok() {
+            let Ok(first) = self else {
+                return false;
+            };
+            let Ok(second) = other else {
+                return false;
+            };
+
+            let approx_eq = first.approx_eq_with_policy(second, tolerance, policy);
+
+            return approx_eq;
+        }
+
+        false
+    }
+}
+
+#[cfg(feature = "num")]
+impl<F: FloatLike> ApproxEqWith for Complex<F> {
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let real_approx_eq = self.re.approx_eq_with_policy(&other.re, tolerance, policy);
+        let imaginary_approx_eq = self.im.approx_eq_with_policy(&other.im, tolerance, policy);
+        let approx_eq = real_approx_eq && imaginary_approx_eq;
+
+        approx_eq
+    }
+}
+
+impl<T: ApproxEqWith> ApproxEqWith for &Option<T> {
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        (**self).approx_eq_with_policy(*other, tolerance, policy)
+    }
+}
+
+impl<T: ApproxEqWith, E> ApproxEqWith for &Result<T, E> {
+    fn approx_eq_with_policy(
+        &self,
+        other: &Self,
+        tolerance: &Tolerance,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        (**self).approx_eq_with_policy(*other, tolerance, policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+
+    #[cfg(feature = "num")]
+    use num::complex::Complex64;
+
+    use super::ApproxEqWith;
+    use crate::tolerance::Tolerance;
+
+    #[test]
+    fn approx_eq_f64() {
+        let a = 100.123_456_789_f64;
+        let b = 100.123_456_712_f64;
+        assert!(a.approx_eq(&b, &Tolerance::Decimals(7)));
+        assert!(a.approx_ne(&b, &Tolerance::Decimals(8)));
+        assert!(a.approx_eq(&b, &Tolerance::SigFigs(9)));
+        assert!(a.approx_eq(&b, &Tolerance::Abs(1e-7)));
+        assert!(a.approx_eq(&b, &Tolerance::Rel(1e-9)));
+        assert!(a.approx_ne(&b, &Tolerance::Ulps(4)));
+
+        let c = 0.1_f64 + 0.2;
+        assert!(c.approx_eq(&0.3, &Tolerance::Ulps(1)));
+    }
+
+    #[test]
+    fn approx_eq_any_all() {
+        let a = 1e-12_f64;
+        let b = 2e-12_f64;
+        let rel_or_abs = Tolerance::Any(vec![Tolerance::Rel(1e-9), Tolerance::Abs(1e-11)]);
+        let rel_and_abs = Tolerance::All(vec![Tolerance::Rel(1e-9), Tolerance::Abs(1e-11)]);
+        assert!(a.approx_eq(&b, &rel_or_abs));
+        assert!(a.approx_ne(&b, &rel_and_abs));
+
+        assert!(a.approx_ne(&b, &Tolerance::Any(vec![])));
+        assert!(a.approx_eq(&b, &Tolerance::All(vec![])));
+    }
+
+    #[test]
+    fn approx_eq_from_config() {
+        let tolerance = "any(sf:4, abs:1e-6)".parse().unwrap();
+        assert!(1234.4_f64.approx_eq(&1234.0, &tolerance));
+        assert!(1234.6_f32.approx_ne(&1234.0, &tolerance));
+    }
+
+    #[test]
+    fn approx_eq_option_result() {
+        let tolerance = Tolerance::Decimals(3);
+
+        let a = Some(1.000_1_f64);
+        let b = Some(1.000_2_f64);
+        assert!(a.approx_eq(&b, &tolerance));
+        assert!(None::<f64>.approx_eq(&None, &tolerance));
+        assert!(a.approx_ne(&None, &tolerance));
+
+        let a = Ok::<f32, Error>(1.000_1_f32);
+        let b = Ok::<f32, Error>(1.000_2_f32);
+        assert!(a.approx_eq(&b, &tolerance));
+
+        let a = Ok::<f32, Error>(1.000_1_f32);
+        let b = Err(Error::msg(""));
+        assert!(a.approx_ne(&b, &tolerance));
+
+        let a = Some(Ok::<f64, Error>(1.000_1_f64));
+        let b = Some(Ok::<f64, Error>(1.000_2_f64));
+        assert!(a.approx_eq(&b, &tolerance));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn approx_eq_complex() {
+        let a = Complex64::new(1.000_1, 2.000_1);
+        let b = Complex64::new(1.000_2, 2.000_2);
+        assert!(a.approx_eq(&b, &Tolerance::Decimals(3)));
+        assert!(a.approx_ne(&b, &Tolerance::SigFigs(5)));
+    }
+}
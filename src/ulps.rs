@@ -0,0 +1,2 @@
+pub mod approx_eq_ulps;
+pub mod next_float;
@@ -0,0 +1,232 @@
+#[cfg(feature = "num")]
+use num::complex::Complex;
+
+use super::next_float::ulps_between;
+use crate::float_like::FloatLike;
+use crate::special_values::SpecialValuePolicy;
+
+pub trait ApproxEqUlps: Sized {
+    /// Whether `self` and `other` are at most `max_ulps` representable values apart, treating
+    /// special values as `policy` specifies.
+    fn aeq_ulps_with_policy(&self, other: Self, max_ulps: u32, policy: &SpecialValuePolicy)
+        -> bool;
+
+    /// Whether `self` and `other` are at most `max_ulps` representable values apart.
+    fn aeq_ulps(&self, other: Self, max_ulps: u32) -> bool {
+        self.aeq_ulps_with_policy(other, max_ulps, &SpecialValuePolicy::default())
+    }
+
+    /// Negation of `aeq_ulps_with_policy`.
+    fn nae_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        !self.aeq_ulps_with_policy(other, max_ulps, policy)
+    }
+
+    /// Negation of `aeq_ulps`.
+    fn nae_ulps(&self, other: Self, max_ulps: u32) -> bool {
+        !self.aeq_ulps(other, max_ulps)
+    }
+}
+
+impl<F: FloatLike> ApproxEqUlps for F {
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if let Some(aeq_ulps) = policy.resolve(*self, other) {
+            return aeq_ulps;
+        }
+
+        let aeq_ulps = ulps_between(*self, other) <= u64::from(max_ulps);
+
+        aeq_ulps
+    }
+}
+
+impl<T: ApproxEqUlps> ApproxEqUlps for Option<T> {
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if self.is_none() && other.is_none() {
+            return policy.none_equal;
+        }
+
+        if self.is_some() && other.is_some() {
+            let Some(first) = self else {
+                return false;
+            };
+            let Some(second) = other else {
+                return false;
+            };
+
+            let aeq_ulps = first.aeq_ulps_with_policy(second, max_ulps, policy);
+
+            return aeq_ulps;
+        }
+
+        false
+    }
+}
+
+impl<T: ApproxEqUlps, E> ApproxEqUlps for Result<T, E> {
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        if self.is_err() && other.is_err() {
+            return policy.err_equal;
+        }
+
+        if self.is_ok() && other.is_ok() {
+            let Ok(first) = self else {
+                return false;
+            };
+            let Ok(second) = other else {
+                return false;
+            };
+
+            let aeq_ulps = first.aeq_ulps_with_policy(second, max_ulps, policy);
+
+            return aeq_ulps;
+        }
+
+        false
+    }
+}
+
+#[cfg(feature = "num")]
+impl<F: FloatLike> ApproxEqUlps for Complex<F> {
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        let real_aeq_ulps = self.re.aeq_ulps_with_policy(other.re, max_ulps, policy);
+        let imaginary_aeq_ulps = self.im.aeq_ulps_with_policy(other.im, max_ulps, policy);
+        let aeq_ulps = real_aeq_ulps && imaginary_aeq_ulps;
+
+        aeq_ulps
+    }
+}
+
+impl<'a, T> ApproxEqUlps for &'a Option<T>
+where
+    &'a T: ApproxEqUlps,
+{
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        Option::as_ref(*self).aeq_ulps_with_policy(other.as_ref(), max_ulps, policy)
+    }
+}
+
+impl<'a, T, E> ApproxEqUlps for &'a Result<T, E>
+where
+    &'a T: ApproxEqUlps,
+{
+    fn aeq_ulps_with_policy(
+        &self,
+        other: Self,
+        max_ulps: u32,
+        policy: &SpecialValuePolicy,
+    ) -> bool {
+        Result::as_ref(*self).aeq_ulps_with_policy(other.as_ref(), max_ulps, policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+
+    #[cfg(feature = "num")]
+    use num::complex::Complex64;
+
+    use super::ApproxEqUlps;
+
+    #[test]
+    fn aeq_ulps_f64() {
+        let a = 0.1_f64 + 0.2;
+        let b = 0.3_f64;
+        assert!(a.aeq_ulps(b, 1));
+        assert!(!a.aeq_ulps(b, 0));
+        assert!(a.nae_ulps(b, 0));
+
+        assert!(0.0_f64.aeq_ulps(-0.0, 0));
+        assert!(!f64::NAN.aeq_ulps(f64::NAN, u32::MAX));
+    }
+
+    #[test]
+    fn aeq_ulps_option_f64() {
+        let a = Some(1.0_f64);
+        let b = Some(1.0 + 4.0 * f64::EPSILON);
+        assert!(a.aeq_ulps(b, 4));
+        assert!(!a.aeq_ulps(b, 3));
+
+        let a = None::<f64>;
+        let b = None;
+        assert!(a.aeq_ulps(b, 0));
+
+        let a = Some(1.0_f64);
+        let b = None;
+        assert!(a.nae_ulps(b, 4));
+    }
+
+    #[test]
+    fn aeq_ulps_result_f64() {
+        let a = Ok::<f64, Error>(1.0_f64);
+        let b = Ok::<f64, Error>(1.0 + 4.0 * f64::EPSILON);
+        assert!(a.aeq_ulps(b, 4));
+
+        let a = Err::<f64, Error>(Error::msg(""));
+        let b = Err(Error::msg(""));
+        assert!(a.aeq_ulps(b, 0));
+
+        let a = Ok::<f64, Error>(1.0_f64);
+        let b = Err(Error::msg(""));
+        assert!(!a.aeq_ulps(b, 4));
+    }
+
+    #[test]
+    fn aeq_ulps_f32() {
+        let a = 1.0_f32;
+        let b = 1.0 + 2.0 * f32::EPSILON;
+        assert!(a.aeq_ulps(b, 2));
+        assert!(!a.aeq_ulps(b, 1));
+
+        let a = Some(1.0_f32);
+        let b = Some(1.0 + 2.0 * f32::EPSILON);
+        assert!(a.aeq_ulps(b, 2));
+
+        let a = Ok::<f32, Error>(-f32::from_bits(1));
+        let b = Ok::<f32, Error>(f32::from_bits(1));
+        assert!(a.aeq_ulps(b, 2));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn aeq_ulps_complex_f64() {
+        let a = Complex64::new(1.0, -1.0);
+        let b = Complex64::new(1.0 + f64::EPSILON, -1.0);
+        assert!(a.aeq_ulps(b, 1));
+        assert!(!a.aeq_ulps(b, 0));
+
+        let a = Some(Complex64::new(1.0, -1.0));
+        let b = None;
+        assert!(a.nae_ulps(b, 1));
+    }
+}
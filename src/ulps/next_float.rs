@@ -0,0 +1,250 @@
+pub trait NextFloat {
+    /// Smallest representable value greater than `self`.
+    fn next_up(self) -> Self;
+
+    /// Largest representable value less than `self`.
+    fn next_down(self) -> Self;
+
+    /// Gap between `|self|` and the next representable value away from zero.
+    fn ulp(self) -> Self;
+
+    /// Number of representable values between `self` and `other`, or `u64::MAX` if either is NaN.
+    ///
+    /// `0.0` and `-0.0` are zero ulps apart.
+    fn ulps_between(self, other: Self) -> u64;
+}
+
+/// Number of representable values between `first` and `second`, or `u64::MAX` if either is NaN.
+pub fn ulps_between<F: NextFloat>(first: F, second: F) -> u64 {
+    first.ulps_between(second)
+}
+
+impl NextFloat for f64 {
+    /// Smallest representable value greater than `self`.
+    fn next_up(self) -> Self {
+        const TINY_BITS: u64 = 0x1;
+        const CLEAR_SIGN_MASK: u64 = 0x7fff_ffff_ffff_ffff;
+
+        let bits = self.to_bits();
+        if self.is_nan() || bits == Self::INFINITY.to_bits() {
+            return self;
+        }
+
+        let abs = bits & CLEAR_SIGN_MASK;
+        let next_bits = if abs == 0 {
+            TINY_BITS
+        } else if bits == abs {
+            bits + 1
+        } else {
+            bits - 1
+        };
+
+        Self::from_bits(next_bits)
+    }
+
+    /// Largest representable value less than `self`.
+    fn next_down(self) -> Self {
+        const NEG_TINY_BITS: u64 = 0x8000_0000_0000_0001;
+        const CLEAR_SIGN_MASK: u64 = 0x7fff_ffff_ffff_ffff;
+
+        let bits = self.to_bits();
+        if self.is_nan() || bits == Self::NEG_INFINITY.to_bits() {
+            return self;
+        }
+
+        let abs = bits & CLEAR_SIGN_MASK;
+        let next_bits = if abs == 0 {
+            NEG_TINY_BITS
+        } else if bits == abs {
+            bits - 1
+        } else {
+            bits + 1
+        };
+
+        Self::from_bits(next_bits)
+    }
+
+    /// Gap between `|self|` and the next representable value away from zero.
+    fn ulp(self) -> Self {
+        const CLEAR_SIGN_MASK: u64 = 0x7fff_ffff_ffff_ffff;
+
+        let abs = Self::from_bits(self.to_bits() & CLEAR_SIGN_MASK);
+        if abs.is_nan() || abs.is_infinite() {
+            return abs;
+        }
+
+        if abs == Self::MAX {
+            return abs - NextFloat::next_down(abs);
+        }
+
+        NextFloat::next_up(abs) - abs
+    }
+
+    /// Number of representable values between `self` and `other`, or `u64::MAX` if either is NaN.
+    fn ulps_between(self, other: Self) -> u64 {
+        const SIGN_BIT: u64 = 0x8000_0000_0000_0000;
+
+        if self.is_nan() || other.is_nan() {
+            return u64::MAX;
+        }
+
+        // Maps the bits onto an unsigned scale that is monotonic in the float value, with both
+        // zeros on the same point.
+        let ordered = |value: Self| {
+            let bits = value.to_bits();
+            if bits & SIGN_BIT == 0 {
+                SIGN_BIT + bits
+            } else {
+                SIGN_BIT - (bits & !SIGN_BIT)
+            }
+        };
+
+        let ulps_between = ordered(self).abs_diff(ordered(other));
+
+        ulps_between
+    }
+}
+
+impl NextFloat for f32 {
+    /// Smallest representable value greater than `self`.
+    fn next_up(self) -> Self {
+        const TINY_BITS: u32 = 0x1;
+        const CLEAR_SIGN_MASK: u32 = 0x7fff_ffff;
+
+        let bits = self.to_bits();
+        if self.is_nan() || bits == Self::INFINITY.to_bits() {
+            return self;
+        }
+
+        let abs = bits & CLEAR_SIGN_MASK;
+        let next_bits = if abs == 0 {
+            TINY_BITS
+        } else if bits == abs {
+            bits + 1
+        } else {
+            bits - 1
+        };
+
+        Self::from_bits(next_bits)
+    }
+
+    /// Largest representable value less than `self`.
+    fn next_down(self) -> Self {
+        const NEG_TINY_BITS: u32 = 0x8000_0001;
+        const CLEAR_SIGN_MASK: u32 = 0x7fff_ffff;
+
+        let bits = self.to_bits();
+        if self.is_nan() || bits == Self::NEG_INFINITY.to_bits() {
+            return self;
+        }
+
+        let abs = bits & CLEAR_SIGN_MASK;
+        let next_bits = if abs == 0 {
+            NEG_TINY_BITS
+        } else if bits == abs {
+            bits - 1
+        } else {
+            bits + 1
+        };
+
+        Self::from_bits(next_bits)
+    }
+
+    /// Gap between `|self|` and the next representable value away from zero.
+    fn ulp(self) -> Self {
+        const CLEAR_SIGN_MASK: u32 = 0x7fff_ffff;
+
+        let abs = Self::from_bits(self.to_bits() & CLEAR_SIGN_MASK);
+        if abs.is_nan() || abs.is_infinite() {
+            return abs;
+        }
+
+        if abs == Self::MAX {
+            return abs - NextFloat::next_down(abs);
+        }
+
+        NextFloat::next_up(abs) - abs
+    }
+
+    /// Number of representable values between `self` and `other`, or `u64::MAX` if either is NaN.
+    fn ulps_between(self, other: Self) -> u64 {
+        const SIGN_BIT: u32 = 0x8000_0000;
+
+        if self.is_nan() || other.is_nan() {
+            return u64::MAX;
+        }
+
+        // Maps the bits onto an unsigned scale that is monotonic in the float value, with both
+        // zeros on the same point.
+        let ordered = |value: Self| {
+            let bits = value.to_bits();
+            if bits & SIGN_BIT == 0 {
+                SIGN_BIT + bits
+            } else {
+                SIGN_BIT - (bits & !SIGN_BIT)
+            }
+        };
+
+        let ulps_between = ordered(self).abs_diff(ordered(other));
+
+        u64::from(ulps_between)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ulps_between, NextFloat};
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn next_up_down_f64() {
+        assert_eq!(NextFloat::next_up(1.0_f64), 1.0 + f64::EPSILON);
+        assert_eq!(NextFloat::next_down(1.0 + f64::EPSILON), 1.0);
+        assert_eq!(NextFloat::next_up(0.0_f64), f64::from_bits(1));
+        assert_eq!(NextFloat::next_up(-0.0_f64), f64::from_bits(1));
+        assert_eq!(NextFloat::next_down(0.0_f64), -f64::from_bits(1));
+        assert_eq!(NextFloat::next_up(-f64::from_bits(1)), -0.0);
+        assert_eq!(NextFloat::next_up(f64::MAX), f64::INFINITY);
+        assert_eq!(NextFloat::next_up(f64::INFINITY), f64::INFINITY);
+        assert_eq!(NextFloat::next_down(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert_eq!(NextFloat::next_up(f64::NEG_INFINITY), f64::MIN);
+        assert!(NextFloat::next_up(f64::NAN).is_nan());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn next_up_down_f32() {
+        assert_eq!(NextFloat::next_up(1.0_f32), 1.0 + f32::EPSILON);
+        assert_eq!(NextFloat::next_down(1.0 + f32::EPSILON), 1.0);
+        assert_eq!(NextFloat::next_up(0.0_f32), f32::from_bits(1));
+        assert_eq!(NextFloat::next_down(-0.0_f32), -f32::from_bits(1));
+        assert_eq!(NextFloat::next_down(f32::MIN), f32::NEG_INFINITY);
+        assert!(NextFloat::next_down(f32::NAN).is_nan());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn ulp() {
+        assert_eq!(NextFloat::ulp(1.0_f64), f64::EPSILON);
+        assert_eq!(NextFloat::ulp(-1.0_f64), f64::EPSILON);
+        assert_eq!(NextFloat::ulp(0.0_f64), f64::from_bits(1));
+        assert_eq!(NextFloat::ulp(1.0_f32), f32::EPSILON);
+        assert!(NextFloat::ulp(f64::MAX).is_finite());
+        assert!(NextFloat::ulp(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn ulps_between_across_zero() {
+        assert_eq!(ulps_between(1.0_f64, 1.0 + f64::EPSILON), 1);
+        assert_eq!(ulps_between(1.0 + f64::EPSILON, 1.0_f64), 1);
+        assert_eq!(ulps_between(0.0_f64, -0.0), 0);
+        assert_eq!(ulps_between(-f64::from_bits(1), f64::from_bits(1)), 2);
+        assert_eq!(ulps_between(-f32::from_bits(3), f32::from_bits(4)), 7);
+        assert_eq!(ulps_between(f32::MAX, f32::INFINITY), 1);
+        assert_eq!(
+            ulps_between(f64::NEG_INFINITY, f64::INFINITY),
+            0xffe0_0000_0000_0000
+        );
+        assert_eq!(ulps_between(f64::NAN, 1.0), u64::MAX);
+    }
+}